@@ -0,0 +1,356 @@
+use crate::jisx0402;
+use crate::to_half_width;
+
+/// 漢数字1文字を数値に変換する（単位となる十・百・千は除く）
+fn kanji_digit(c: char) -> Option<u32> {
+    match c {
+        '〇' | '零' => Some(0),
+        '一' => Some(1),
+        '二' => Some(2),
+        '三' => Some(3),
+        '四' => Some(4),
+        '五' => Some(5),
+        '六' => Some(6),
+        '七' => Some(7),
+        '八' => Some(8),
+        '九' => Some(9),
+        _ => None,
+    }
+}
+
+/// 漢数字の単位（十・百・千）を数値に変換する
+fn kanji_unit(c: char) -> Option<u32> {
+    match c {
+        '十' => Some(10),
+        '百' => Some(100),
+        '千' => Some(1000),
+        _ => None,
+    }
+}
+
+fn is_kanji_numeral(c: char) -> bool {
+    kanji_digit(c).is_some() || kanji_unit(c).is_some()
+}
+
+/// 漢数字の並びを数値に変換する関数
+///
+/// 「十二」「二百三」のような単位を含む表記と、「一〇五」のような位取りの表記の両方に対応する。
+/// 漢数字として解釈できない場合や、`u32`に収まらない場合は`None`を返す。
+pub fn kanji_to_number(s: &str) -> Option<u32> {
+    if s.is_empty() {
+        return None;
+    }
+
+    // 単位を含まない場合は位取り記数法として扱う（例: 「一〇五」→105）
+    if !s.chars().any(|c| kanji_unit(c).is_some()) {
+        return s
+            .chars()
+            .try_fold(0u32, |acc, c| acc.checked_mul(10)?.checked_add(kanji_digit(c)?));
+    }
+
+    let mut total = 0;
+    let mut digit: Option<u32> = None;
+    let mut last_unit = u32::MAX;
+    for c in s.chars() {
+        if let Some(d) = kanji_digit(c) {
+            // 「二三十」のように数字が連続する表記は解釈しない
+            if digit.is_some() {
+                return None;
+            }
+            digit = Some(d);
+        } else if let Some(unit) = kanji_unit(c) {
+            // 「十百」のように単位が大きくなる表記は解釈しない
+            if unit >= last_unit {
+                return None;
+            }
+            total += digit.take().unwrap_or(1) * unit;
+            last_unit = unit;
+        } else {
+            return None;
+        }
+    }
+    Some(total + digit.unwrap_or(0))
+}
+
+/// 漢数字の直後に続いた場合に数値として扱う接尾辞
+const NUMERAL_SUFFIXES: [&str; 4] = ["丁目", "番地", "番", "号"];
+
+/// 「一番町」のように町名の一部となっている場合は変換しない
+const NUMERAL_SUFFIX_EXCEPTIONS: [&str; 2] = ["番町", "番丁"];
+
+/// 丁目・番地・番・号の直前にある漢数字を算用数字に変換する関数
+///
+/// 例: 「呼続四丁目」→「呼続4丁目」、「十二番地」→「12番地」
+/// 「九十九里町」のように地名の一部となっている漢数字は変換しない。
+pub fn convert_kanji_numerals(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find(is_kanji_numeral) {
+        result.push_str(&rest[..start]);
+        let run = &rest[start..];
+        let end = run.find(|c: char| !is_kanji_numeral(c)).unwrap_or(run.len());
+        let (numeral, after) = run.split_at(end);
+
+        let is_numeral_context = NUMERAL_SUFFIXES.iter().any(|suffix| after.starts_with(suffix))
+            && !NUMERAL_SUFFIX_EXCEPTIONS.iter().any(|suffix| after.starts_with(suffix));
+        match is_numeral_context.then(|| kanji_to_number(numeral)).flatten() {
+            Some(number) => result.push_str(&number.to_string()),
+            None => result.push_str(numeral),
+        }
+        rest = after;
+    }
+    result.push_str(rest);
+    result
+}
+
+/// 住所を比較可能な形に正規化する関数
+///
+/// 全角数字の半角化、空白の除去、丁目・番地などに付く漢数字の算用数字化を行う。
+/// ソースによって表記が異なる同一の住所が、同じ文字列となるようにする。
+pub fn normalize_address(address: &str) -> String {
+    let compact = to_half_width(address)
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>();
+    convert_kanji_numerals(&compact)
+}
+
+/// 都道府県コード（地方公共団体コードの上2桁）と都道府県名の対応表
+pub const PREFECTURES: [(&str, &str); 47] = [
+    ("01", "北海道"),
+    ("02", "青森県"),
+    ("03", "岩手県"),
+    ("04", "宮城県"),
+    ("05", "秋田県"),
+    ("06", "山形県"),
+    ("07", "福島県"),
+    ("08", "茨城県"),
+    ("09", "栃木県"),
+    ("10", "群馬県"),
+    ("11", "埼玉県"),
+    ("12", "千葉県"),
+    ("13", "東京都"),
+    ("14", "神奈川県"),
+    ("15", "新潟県"),
+    ("16", "富山県"),
+    ("17", "石川県"),
+    ("18", "福井県"),
+    ("19", "山梨県"),
+    ("20", "長野県"),
+    ("21", "岐阜県"),
+    ("22", "静岡県"),
+    ("23", "愛知県"),
+    ("24", "三重県"),
+    ("25", "滋賀県"),
+    ("26", "京都府"),
+    ("27", "大阪府"),
+    ("28", "兵庫県"),
+    ("29", "奈良県"),
+    ("30", "和歌山県"),
+    ("31", "鳥取県"),
+    ("32", "島根県"),
+    ("33", "岡山県"),
+    ("34", "広島県"),
+    ("35", "山口県"),
+    ("36", "徳島県"),
+    ("37", "香川県"),
+    ("38", "愛媛県"),
+    ("39", "高知県"),
+    ("40", "福岡県"),
+    ("41", "佐賀県"),
+    ("42", "長崎県"),
+    ("43", "熊本県"),
+    ("44", "大分県"),
+    ("45", "宮崎県"),
+    ("46", "鹿児島県"),
+    ("47", "沖縄県"),
+];
+
+/// 区を持つ政令指定都市
+const DESIGNATED_CITIES: [&str; 20] = [
+    "札幌市", "仙台市", "さいたま市", "千葉市", "横浜市", "川崎市", "相模原市", "新潟市", "静岡市", "浜松市",
+    "名古屋市", "京都市", "大阪市", "堺市", "神戸市", "岡山市", "広島市", "北九州市", "福岡市", "熊本市",
+];
+
+/// 名称の途中に「郡」「市」「町」「村」を含み、単純な区切りでは誤って分割される市町村
+const IRREGULAR_MUNICIPALITIES: [&str; 16] = [
+    "大和郡山市", "郡山市", "四日市市", "廿日市市", "野々市市", "上市町", "大町市", "大町町",
+    "十日町市", "東村山市", "武蔵村山市", "村山市", "羽村市", "大村市", "田村市", "玉村町",
+];
+
+lazy_static::lazy_static! {
+    // 「田村郡」「西村山郡」「高市郡」のように郡名に「市」「村」を含む場合も郡として扱う
+    static ref COUNTY_RE: regex::Regex = regex::Regex::new(r"^[^市町村郡][^町郡]{0,4}?郡").unwrap();
+    static ref MUNICIPALITY_RE: regex::Regex = regex::Regex::new(r"^.+?[市町村]").unwrap();
+    static ref TOKYO_MUNICIPALITY_RE: regex::Regex = regex::Regex::new(r"^.+?[市区町村]").unwrap();
+    static ref WARD_RE: regex::Regex = regex::Regex::new(r"^.+?区").unwrap();
+    static ref CHOME_RE: regex::Regex = regex::Regex::new(r"^(.*?)(\d+)丁目(.*)$").unwrap();
+}
+
+/// 地方公共団体コード（または都道府県コード）から都道府県名を返す関数
+pub fn prefecture_name(code: &str) -> Option<&'static str> {
+    let prefix = code.get(..2)?;
+    PREFECTURES
+        .iter()
+        .find(|(pref_code, _)| *pref_code == prefix)
+        .map(|(_, name)| *name)
+}
+
+/// 住所を構成要素に分解した結果
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParsedAddress {
+    /// 都道府県名
+    pub prefecture: Option<String>,
+    /// 住所に都道府県名が含まれていたか（含まれていない場合は地方公共団体コードから補完）
+    pub prefecture_in_address: bool,
+    /// 市区町村名（郡名は含まない。東京都の特別区はここに入る）
+    pub municipality: Option<String>,
+    /// 政令指定都市の区名
+    pub ward: Option<String>,
+    /// 町名・大字名
+    pub town: Option<String>,
+    /// 丁目
+    pub chome: Option<u32>,
+    /// 上記に当てはまらない残りの部分（番地など）
+    pub remainder: Option<String>,
+}
+
+impl ParsedAddress {
+    /// 市区町村（政令指定都市の場合は区）より後ろの部分を返す
+    pub fn local_part(&self) -> String {
+        let chome = self.chome.map(|chome| format!("{}丁目", chome));
+        [self.town.as_deref(), chome.as_deref(), self.remainder.as_deref()]
+            .iter()
+            .flatten()
+            .copied()
+            .collect()
+    }
+}
+
+fn non_empty(s: &str) -> Option<String> {
+    if s.is_empty() { None } else { Some(s.to_string()) }
+}
+
+/// 住所の先頭が地方公共団体コード表または`IRREGULAR_MUNICIPALITIES`の市区町村名と一致すれば、その名称を返す
+fn known_municipality(rest: &str, prefecture: Option<&str>) -> Option<&'static str> {
+    jisx0402::match_municipality(prefecture, rest)
+        .map(|government| government.name)
+        .or_else(|| IRREGULAR_MUNICIPALITIES.iter().find(|name| rest.starts_with(*name)).copied())
+}
+
+/// 住所の先頭から市区町村名を取り出す
+fn split_municipality<'a>(rest: &'a str, prefecture: Option<&str>) -> Option<(&'a str, &'a str)> {
+    if let Some(name) = known_municipality(rest, prefecture) {
+        return Some(rest.split_at(name.len()));
+    }
+    let re: &regex::Regex = if prefecture == Some("東京都") { &TOKYO_MUNICIPALITY_RE } else { &MUNICIPALITY_RE };
+    re.find(rest).map(|m| rest.split_at(m.end()))
+}
+
+/// 住所文字列を都道府県・市区町村・区・町名・丁目・残りに分解する関数
+///
+/// 住所は`normalize_address`で正規化されていることを前提とする。
+/// 住所に都道府県名が無い場合は、`jisx0402`が与えられていればそこから補完する。
+pub fn parse_address(address: &str, jisx0402: Option<&str>) -> ParsedAddress {
+    let mut parsed = ParsedAddress::default();
+    let mut rest = address;
+
+    if let Some((_, name)) = PREFECTURES.iter().find(|(_, name)| rest.starts_with(*name)) {
+        parsed.prefecture = Some(name.to_string());
+        parsed.prefecture_in_address = true;
+        rest = &rest[name.len()..];
+    } else {
+        parsed.prefecture = jisx0402.and_then(prefecture_name).map(str::to_string);
+    }
+
+    // 郡名は市区町村の特定に使わないため読み飛ばす（例: 「入間郡毛呂山町」）
+    // 「上郡町」のように郡の直後が「市」「町」「村」となる場合は、郡ではなく市町村名の一部とみなす
+    if known_municipality(rest, parsed.prefecture.as_deref()).is_none()
+        && let Some(m) = COUNTY_RE.find(rest)
+        && !rest[m.end()..].starts_with(['市', '町', '村'])
+    {
+        rest = &rest[m.end()..];
+    }
+
+    let Some((municipality, after)) = split_municipality(rest, parsed.prefecture.as_deref()) else {
+        parsed.remainder = non_empty(rest);
+        return parsed;
+    };
+    parsed.municipality = Some(municipality.to_string());
+    rest = after;
+
+    if DESIGNATED_CITIES.contains(&municipality)
+        && let Some(m) = WARD_RE.find(rest)
+    {
+        parsed.ward = Some(m.as_str().to_string());
+        rest = &rest[m.end()..];
+    }
+
+    if let Some(caps) = CHOME_RE.captures(rest) {
+        parsed.town = non_empty(&caps[1]);
+        parsed.chome = caps[2].parse().ok();
+        parsed.remainder = non_empty(&caps[3]);
+    } else {
+        let town_end = rest.find(|c: char| c.is_ascii_digit() || c == '-').unwrap_or(rest.len());
+        parsed.town = non_empty(&rest[..town_end]);
+        parsed.remainder = non_empty(&rest[town_end..]);
+    }
+    parsed
+}
+
+/// 分解した住所が地方公共団体コードと矛盾していないかを確認し、矛盾があれば内容を返す関数
+///
+/// 市区町村名は、住所の市区町村名と地方公共団体コードがどちらも地方公共団体コード表にある場合に限り照合する。
+pub fn validate_address(parsed: &ParsedAddress, jisx0402: &str) -> Option<String> {
+    let expected = prefecture_name(jisx0402)?;
+    let prefecture = parsed.prefecture.as_deref();
+    if let Some(prefecture) = prefecture
+        && parsed.prefecture_in_address
+        && prefecture != expected
+    {
+        return Some(format!(
+            "都道府県「{}」が地方公共団体コード{}の都道府県「{}」と一致しません",
+            prefecture, jisx0402, expected
+        ));
+    }
+
+    let government = jisx0402::lookup(jisx0402).filter(|government| !government.is_prefecture())?;
+    let municipality = parsed.municipality.as_deref()?;
+    let known = jisx0402::match_municipality(prefecture, municipality)
+        .is_some_and(|known| known.name == municipality);
+    if known && municipality != government.name {
+        return Some(format!(
+            "市区町村「{}」が地方公共団体コード{}の市区町村「{}」と一致しません",
+            municipality, jisx0402, government.name
+        ));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_numerals_before_suffixes() {
+        assert_eq!(convert_kanji_numerals("呼続四丁目"), "呼続4丁目");
+        assert_eq!(convert_kanji_numerals("本町十二番地"), "本町12番地");
+        assert_eq!(convert_kanji_numerals("二十三号"), "23号");
+        assert_eq!(convert_kanji_numerals("一〇五番"), "105番");
+    }
+
+    #[test]
+    fn keeps_numerals_in_place_names() {
+        assert_eq!(convert_kanji_numerals("九十九里町"), "九十九里町");
+        assert_eq!(convert_kanji_numerals("一番町"), "一番町");
+    }
+
+    #[test]
+    fn keeps_long_digit_runs_without_overflow() {
+        let run = "九".repeat(40);
+        assert_eq!(kanji_to_number(&run), None);
+        assert_eq!(convert_kanji_numerals(&format!("{}番地", run)), format!("{}番地", run));
+        assert_eq!(convert_kanji_numerals(&format!("{}町", run)), format!("{}町", run));
+    }
+}
@@ -0,0 +1,124 @@
+use std::fs;
+use std::io::{BufRead, BufReader, Lines, Write};
+use std::path::{Path, PathBuf};
+use chrono::{DateTime, FixedOffset, NaiveDate, SecondsFormat};
+use serde_json::{json, Value};
+
+use crate::state::Observation;
+
+/// 出動情報の履歴を保存するディレクトリの既定の場所
+pub const DEFAULT_ARCHIVE_DIR: &str = "archive";
+
+/// 出動情報の履歴を保存するディレクトリを指定する環境変数
+pub const ARCHIVE_DIR_ENV: &str = "EMERGENCY_DISPATCH_ARCHIVE";
+
+/// 履歴を保存するディレクトリ（環境変数`EMERGENCY_DISPATCH_ARCHIVE`、無ければ`archive`）
+pub fn archive_dir() -> PathBuf {
+    PathBuf::from(std::env::var(ARCHIVE_DIR_ENV).unwrap_or_else(|_| DEFAULT_ARCHIVE_DIR.to_string()))
+}
+
+/// 日付ごとの履歴ファイルの場所（`（ディレクトリ）/YYYY-MM-DD.jsonl`）
+pub fn archive_path(dir: &Path, date: NaiveDate) -> PathBuf {
+    dir.join(format!("{}.jsonl", date.format("%Y-%m-%d")))
+}
+
+/// 履歴の1行を生成する関数
+///
+/// 初めて観測した出動情報は`event`を`new`、状況が変わった出動情報は`status_changed`とし、
+/// 正規化などを行う前のパーサーの出力を`parser_output`に格納する（ソースから取得した文面そのものではない）。
+/// 状況が変わっていない出動情報は`None`を返す。
+pub fn entry(
+    jisx0402: &str,
+    id: &str,
+    observation: &Observation,
+    parser_output: &Value,
+    disaster: &Value,
+    observed_at: DateTime<FixedOffset>,
+) -> Option<Value> {
+    let (event, previous_status) = match observation {
+        Observation::New => ("new", Value::Null),
+        Observation::Changed(_) => ("status_changed", observation.status_change()?.from.clone()),
+        Observation::Unchanged => return None,
+    };
+    Some(json!({
+        "event": event,
+        "id": id,
+        "jisx0402": jisx0402,
+        "observed_at": observed_at.to_rfc3339_opts(SecondsFormat::Secs, false),
+        "time": disaster["time"],
+        "type": disaster["type"],
+        "address": disaster["address"],
+        "status": disaster["status"],
+        "previous_status": previous_status,
+        "parser_output": parser_output
+    }))
+}
+
+/// 履歴の1行を、観測日のファイルの末尾へ追記する関数
+pub fn append(dir: &Path, entry: &Value, observed_at: DateTime<FixedOffset>) -> Result<(), Box<dyn std::error::Error>> {
+    fs::create_dir_all(dir)?;
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(archive_path(dir, observed_at.date_naive()))?;
+    // 1行を1回の書き込みで追記し、他の行と混ざらないようにする
+    file.write_all(format!("{}\n", entry).as_bytes())?;
+    Ok(())
+}
+
+/// 観測結果を`dir`の履歴へ追記する関数（変化の無い出動情報は追記しない）
+pub fn record(
+    dir: &Path,
+    jisx0402: &str,
+    id: &str,
+    observation: &Observation,
+    parser_output: &Value,
+    disaster: &Value,
+    observed_at: DateTime<FixedOffset>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match entry(jisx0402, id, observation, parser_output, disaster, observed_at) {
+        Some(entry) => append(dir, &entry, observed_at),
+        None => Ok(()),
+    }
+}
+
+/// 期間内の履歴を日付順に1行ずつ返すイテレーター
+///
+/// 履歴ファイルが無い日は読み飛ばす。解釈できない行はエラーとして返し、続く行の読み込みは継続する。
+pub struct ArchiveIter {
+    dir: PathBuf,
+    /// 次に開く履歴ファイルの日付
+    next_date: Option<NaiveDate>,
+    to: NaiveDate,
+    lines: Option<Lines<BufReader<fs::File>>>,
+}
+
+impl Iterator for ArchiveIter {
+    type Item = Result<Value, Box<dyn std::error::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(lines) = &mut self.lines {
+                match lines.next() {
+                    Some(Ok(line)) if line.trim().is_empty() => continue,
+                    Some(Ok(line)) => return Some(serde_json::from_str(&line).map_err(|e| e.into())),
+                    Some(Err(e)) => return Some(Err(e.into())),
+                    None => self.lines = None,
+                }
+            }
+
+            let date = self.next_date.filter(|date| *date <= self.to)?;
+            self.next_date = date.succ_opt();
+            match fs::File::open(archive_path(&self.dir, date)) {
+                Ok(file) => self.lines = Some(BufReader::new(file).lines()),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Some(Err(e.into())),
+            }
+        }
+    }
+}
+
+/// `from`から`to`まで（両端を含む）の履歴を日付順に読み込む関数
+pub fn iter_range(dir: &Path, from: NaiveDate, to: NaiveDate) -> ArchiveIter {
+    ArchiveIter { dir: dir.to_path_buf(), next_date: Some(from), to, lines: None }
+}
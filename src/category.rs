@@ -0,0 +1,153 @@
+/// 出動種別の大分類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+    /// 火災
+    Fire,
+    /// 救助
+    Rescue,
+    /// 救急支援（救急隊との連携・救急車の応援など）
+    AmbulanceSupport,
+    /// 危険物（ガス漏れ・油の流出など）
+    Hazmat,
+    /// 警戒・調査
+    Alarm,
+    /// その他
+    Other,
+}
+
+impl Category {
+    /// 全ての大分類
+    pub const ALL: [Category; 6] = [
+        Category::Fire,
+        Category::Rescue,
+        Category::AmbulanceSupport,
+        Category::Hazmat,
+        Category::Alarm,
+        Category::Other,
+    ];
+
+    /// 出力に使用する識別子
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Category::Fire => "fire",
+            Category::Rescue => "rescue",
+            Category::AmbulanceSupport => "ambulance_support",
+            Category::Hazmat => "hazmat",
+            Category::Alarm => "alarm",
+            Category::Other => "other",
+        }
+    }
+
+    /// 日本語の名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            Category::Fire => "火災",
+            Category::Rescue => "救助",
+            Category::AmbulanceSupport => "救急支援",
+            Category::Hazmat => "危険物",
+            Category::Alarm => "警戒",
+            Category::Other => "その他",
+        }
+    }
+}
+
+/// 火災の小分類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Subcategory {
+    /// 建物火災
+    Building,
+    /// 車両火災
+    Vehicle,
+    /// 林野火災
+    Forest,
+}
+
+impl Subcategory {
+    /// 全ての小分類
+    pub const ALL: [Subcategory; 3] = [Subcategory::Building, Subcategory::Vehicle, Subcategory::Forest];
+
+    /// 出力に使用する識別子
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Subcategory::Building => "building",
+            Subcategory::Vehicle => "vehicle",
+            Subcategory::Forest => "forest",
+        }
+    }
+}
+
+/// 出動種別の文字列と分類の対応表
+///
+/// 上から順に照合し、最初に部分一致したものを採用する。
+/// より具体的な表現（「建物火災」など）を、一般的な表現（「火災」など）より先に並べること。
+const CATEGORY_TABLE: &[(&str, Category, Option<Subcategory>)] = &[
+    ("建物火災", Category::Fire, Some(Subcategory::Building)),
+    ("住宅火災", Category::Fire, Some(Subcategory::Building)),
+    ("車両火災", Category::Fire, Some(Subcategory::Vehicle)),
+    ("自動車火災", Category::Fire, Some(Subcategory::Vehicle)),
+    ("船舶火災", Category::Fire, Some(Subcategory::Vehicle)),
+    ("林野火災", Category::Fire, Some(Subcategory::Forest)),
+    ("山林火災", Category::Fire, Some(Subcategory::Forest)),
+    ("枯草火災", Category::Fire, Some(Subcategory::Forest)),
+    ("危険物", Category::Hazmat, None),
+    ("ガス漏", Category::Hazmat, None),
+    ("油漏", Category::Hazmat, None),
+    ("漏洩", Category::Hazmat, None),
+    ("流出", Category::Hazmat, None),
+    ("救急", Category::AmbulanceSupport, None),
+    ("PA連携", Category::AmbulanceSupport, None),
+    ("救助", Category::Rescue, None),
+    ("水難", Category::Rescue, None),
+    ("山岳", Category::Rescue, None),
+    ("交通事故", Category::Rescue, None),
+    ("事故", Category::Rescue, None),
+    ("警戒", Category::Alarm, None),
+    ("警報", Category::Alarm, None),
+    ("報知", Category::Alarm, None),
+    ("調査", Category::Alarm, None),
+    ("確認", Category::Alarm, None),
+    ("火災", Category::Fire, None),
+    ("火事", Category::Fire, None),
+];
+
+/// 出動種別の文字列を分類する関数
+///
+/// `type`として出力される元の文字列はそのまま残し、分類は別のフィールドとして付与する。
+/// 対応表に当てはまらない場合は`Category::Other`となる。
+pub fn classify(disaster_type: &str) -> (Category, Option<Subcategory>) {
+    let disaster_type = disaster_type.trim();
+    CATEGORY_TABLE
+        .iter()
+        .find(|(keyword, _, _)| disaster_type.contains(keyword))
+        .map(|(_, category, subcategory)| (*category, *subcategory))
+        .unwrap_or((Category::Other, None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_fires_with_subcategories() {
+        assert_eq!(classify("建物火災"), (Category::Fire, Some(Subcategory::Building)));
+        assert_eq!(classify(" 車両火災 "), (Category::Fire, Some(Subcategory::Vehicle)));
+        assert_eq!(classify("枯草火災"), (Category::Fire, Some(Subcategory::Forest)));
+        assert_eq!(classify("その他火災"), (Category::Fire, None));
+    }
+
+    #[test]
+    fn prefers_the_more_specific_entry() {
+        // 「火災」を含んでも、先に並べた分類を採用する
+        assert_eq!(classify("危険物火災"), (Category::Hazmat, None));
+        assert_eq!(classify("自動火災報知設備鳴動"), (Category::Alarm, None));
+        assert_eq!(classify("救急支援"), (Category::AmbulanceSupport, None));
+        assert_eq!(classify("救急救助"), (Category::AmbulanceSupport, None));
+        assert_eq!(classify("交通事故"), (Category::Rescue, None));
+    }
+
+    #[test]
+    fn falls_back_to_other() {
+        assert_eq!(classify("航空隊支援"), (Category::Other, None));
+        assert_eq!(classify(""), (Category::Other, None));
+    }
+}
@@ -0,0 +1,174 @@
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use serde_json::Value;
+
+use crate::archive;
+#[cfg(feature = "sqlite")]
+use crate::database;
+use crate::feed::now_jst;
+use crate::health::RunHealth;
+use crate::output::{self, OutputSink};
+use crate::state::{self, IncidentStore};
+
+/// 1回の実行で使用する出力先と実行中の状態、実行をまたいで保持するファイルの場所
+///
+/// `get_all`や各生成関数はこれを受け取って読み書きするため、作業ディレクトリやプロセス内の他の実行には依存しない。
+/// 状態ファイル・履歴・データベースの場所を指定しない場合、出動情報の状態はメモリ上でのみ保持し、履歴とデータベースには書き込まない。
+pub struct RunContext {
+    /// 生成したファイルの出力先
+    sink: Mutex<Box<dyn OutputSink>>,
+    /// 出動情報の状態
+    store: Mutex<IncidentStore>,
+    /// パーサーの実行結果
+    health: Mutex<RunHealth>,
+    /// 構成市町村に振り分けられなかった出動情報
+    unattributed: Mutex<Vec<Value>>,
+    /// 出動情報の履歴を保存するディレクトリ
+    archive_dir: Option<PathBuf>,
+    /// SQLiteのデータベースファイルの場所
+    database_path: Option<PathBuf>,
+}
+
+impl RunContext {
+    /// 出力先を指定して作成する
+    pub fn new(sink: Box<dyn OutputSink>) -> Self {
+        RunContext {
+            sink: Mutex::new(sink),
+            store: Mutex::new(IncidentStore::new(now_jst())),
+            health: Mutex::new(RunHealth::default()),
+            unattributed: Mutex::new(vec![]),
+            archive_dir: None,
+            database_path: None,
+        }
+    }
+
+    /// 環境変数で指定された場所、無ければ作業ディレクトリの既定の場所を使用して作成する
+    pub fn from_env() -> Result<Self, Box<dyn std::error::Error>> {
+        let context = RunContext::new(output::sink_for(&output::output_dir())?)
+            .with_state_path(state::state_path())
+            .with_archive_dir(archive::archive_dir());
+        #[cfg(feature = "sqlite")]
+        let context = context.with_database_path(database::database_path());
+        Ok(context)
+    }
+
+    /// 状態ファイルを読み込み、`save_incident_state`で書き出す場所とする
+    pub fn with_state_path(mut self, path: impl AsRef<Path>) -> Self {
+        self.store = Mutex::new(IncidentStore::load(path.as_ref(), now_jst()));
+        self
+    }
+
+    pub fn with_archive_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.archive_dir = Some(dir.into());
+        self
+    }
+
+    pub fn with_database_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.database_path = Some(path.into());
+        self
+    }
+
+    /// 出動情報の履歴を保存するディレクトリ（指定されていない場合は`None`）
+    pub fn archive_dir(&self) -> Option<&Path> {
+        self.archive_dir.as_deref()
+    }
+
+    /// SQLiteのデータベースファイルの場所（指定されていない場合は`None`）
+    pub fn database_path(&self) -> Option<&Path> {
+        self.database_path.as_deref()
+    }
+
+    /// 出動情報の状態
+    pub fn state(&self) -> MutexGuard<'_, IncidentStore> {
+        self.store.lock().unwrap()
+    }
+
+    /// パーサーの実行結果（`get_all`が記録し、`status.json`などの生成に使用する）
+    pub fn health(&self) -> MutexGuard<'_, RunHealth> {
+        self.health.lock().unwrap()
+    }
+
+    /// 構成市町村に振り分けられなかった出動情報（`save_department_json`が追加し、`generate_unattributed_json`が書き出す）
+    pub fn unattributed(&self) -> MutexGuard<'_, Vec<Value>> {
+        self.unattributed.lock().unwrap()
+    }
+
+    /// ファイルを出力先へ書き出す
+    pub fn write(&self, path: &str, contents: impl AsRef<[u8]>) -> Result<(), Box<dyn std::error::Error>> {
+        self.sink.lock().unwrap().write(path, contents.as_ref())
+    }
+
+    /// 出力先のファイルを読み込む（ファイルが無い場合は`None`）
+    pub fn read(&self, path: &str) -> Result<Option<Vec<u8>>, Box<dyn std::error::Error>> {
+        self.sink.lock().unwrap().read(path)
+    }
+
+    /// 出力先のファイルを文字列として読み込む（ファイルが無い場合は`None`）
+    pub fn read_to_string(&self, path: &str) -> Result<Option<String>, Box<dyn std::error::Error>> {
+        match self.read(path)? {
+            Some(contents) => Ok(Some(String::from_utf8(contents)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// 出力先の全てのファイルのパスを返す
+    pub fn list(&self) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        self.sink.lock().unwrap().list()
+    }
+
+    /// 表示用のファイルの場所を返す
+    pub fn location(&self, path: &str) -> String {
+        self.sink.lock().unwrap().location(path)
+    }
+
+    /// 全てのファイルを書き出した後に、出力先での後処理（tarアーカイブの書き出しなど）を行う
+    pub fn finish(&self) -> Result<(), Box<dyn std::error::Error>> {
+        self.sink.lock().unwrap().finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::output::MemorySink;
+
+    #[test]
+    fn writes_only_to_the_given_sink() {
+        let sink = MemorySink::new();
+        let ctx = RunContext::new(Box::new(sink.clone()));
+        crate::save_disaster_json(
+            &ctx,
+            &json!({
+                "jisx0402": "011002",
+                "source": [{ "url": "https://example.jp/", "name": "札幌市消防局" }],
+                "disasters": [{ "type": "建物火災", "address": "北海道札幌市中央区北1条西2丁目", "time": "10:00" }]
+            }),
+        )
+        .unwrap();
+        crate::save_incident_state(&ctx).unwrap();
+
+        assert_eq!(sink.files().keys().collect::<Vec<_>>(), vec!["011002.json"]);
+        assert!(ctx.state().path().is_none());
+        assert_eq!(ctx.state().len(), 1);
+    }
+
+    #[test]
+    fn keeps_run_state_per_context() {
+        let ctx = RunContext::new(Box::new(MemorySink::new()));
+        let other = RunContext::new(Box::new(MemorySink::new()));
+        crate::save_department_json(
+            &ctx,
+            "sapporo",
+            json!([{ "url": "https://example.jp/", "name": "札幌市消防局" }]),
+            vec![json!({ "type": "救急", "address": "北海道函館市五稜郭町", "time": "10:00" })],
+        )
+        .unwrap();
+
+        assert_eq!(ctx.unattributed().len(), 1);
+        assert!(other.unattributed().is_empty());
+        assert!(other.health().attempts().is_empty());
+    }
+}
@@ -0,0 +1,304 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use chrono::{DateTime, FixedOffset, SecondsFormat};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde_json::{json, Value};
+
+use crate::context::RunContext;
+use crate::envelope;
+use crate::feed::incident_datetime;
+use crate::health::SourceStatus;
+
+/// データベースファイルの既定の配置場所
+pub const DEFAULT_DATABASE_PATH: &str = "state/emergency_dispatch.sqlite3";
+
+/// データベースファイルの場所を指定する環境変数
+pub const DATABASE_PATH_ENV: &str = "EMERGENCY_DISPATCH_DATABASE";
+
+/// データベースのスキーマ
+///
+/// 日時はすべて日本標準時のRFC 3339形式（例: `2026-10-19T12:34:00+09:00`）の文字列で保存するため、文字列の比較で前後を判定できる。
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS runs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    observed_at TEXT NOT NULL,
+    source_count INTEGER NOT NULL,
+    incident_count INTEGER NOT NULL,
+    failed_parser_count INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS sources (
+    jisx0402 TEXT PRIMARY KEY,
+    name TEXT,
+    url TEXT,
+    last_seen TEXT NOT NULL,
+    last_run_id INTEGER NOT NULL REFERENCES runs(id),
+    incident_count INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS incidents (
+    id TEXT PRIMARY KEY,
+    jisx0402 TEXT NOT NULL,
+    time TEXT,
+    type TEXT,
+    category TEXT,
+    subcategory TEXT,
+    status TEXT,
+    address TEXT,
+    lat REAL,
+    lon REAL,
+    first_seen TEXT NOT NULL,
+    last_seen TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS incidents_jisx0402_time ON incidents (jisx0402, time);
+CREATE TABLE IF NOT EXISTS observations (
+    run_id INTEGER NOT NULL REFERENCES runs(id),
+    incident_id TEXT NOT NULL REFERENCES incidents(id),
+    observed_at TEXT NOT NULL,
+    status TEXT,
+    PRIMARY KEY (run_id, incident_id)
+);
+";
+
+/// データベースファイルの場所（環境変数`EMERGENCY_DISPATCH_DATABASE`、無ければ`state/emergency_dispatch.sqlite3`）
+pub fn database_path() -> PathBuf {
+    PathBuf::from(std::env::var(DATABASE_PATH_ENV).unwrap_or_else(|_| DEFAULT_DATABASE_PATH.to_string()))
+}
+
+/// 日時を秒単位のRFC 3339形式とする
+fn format_datetime(datetime: DateTime<FixedOffset>) -> String {
+    datetime.to_rfc3339_opts(SecondsFormat::Secs, false)
+}
+
+/// データベースを開き、テーブルが無ければ作成する関数
+pub fn open(path: &Path) -> Result<Connection, Box<dyn std::error::Error>> {
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    let connection = Connection::open(path)?;
+    connection.execute_batch(SCHEMA)?;
+    Ok(connection)
+}
+
+/// 1回の実行の結果を、市区町村ごとのJSONファイルからデータベースへ書き込む関数
+///
+/// 実行の記録を`runs`へ追加し、ソース（`sources`）と出動情報（`incidents`）を更新した上で、
+/// 今回観測した出動情報を`observations`へ記録する。追加した実行の番号を返す。
+/// 取得に失敗したソース（`status`が`stale`・`unavailable`のファイル）は今回観測したものではないため、記録しない。
+pub fn record_run(
+    ctx: &RunContext,
+    connection: &mut Connection,
+    files: &[String],
+    observed_at: DateTime<FixedOffset>,
+    failed_parser_count: usize,
+) -> Result<i64, Box<dyn std::error::Error>> {
+    let observed_at_str = format_datetime(observed_at);
+    let transaction = connection.transaction()?;
+    transaction.execute(
+        "INSERT INTO runs (observed_at, source_count, incident_count, failed_parser_count) VALUES (?1, 0, 0, ?2)",
+        params![observed_at_str, failed_parser_count as i64],
+    )?;
+    let run_id = transaction.last_insert_rowid();
+    let mut source_count = 0;
+    let mut incident_count = 0;
+
+    for file in files {
+        let Some(json) = envelope::read(ctx, file)? else {
+            continue;
+        };
+        let Some(jisx0402) = json["jisx0402"].as_str() else {
+            continue;
+        };
+        if json["status"].as_str().is_some_and(|status| status != SourceStatus::Ok.as_str()) {
+            continue;
+        }
+        source_count += 1;
+        let disasters = json["disasters"].as_array().map(Vec::as_slice).unwrap_or_default();
+        let source = json["source"].as_array().and_then(|source| source.first());
+
+        transaction.execute(
+            "INSERT INTO sources (jisx0402, name, url, last_seen, last_run_id, incident_count)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT (jisx0402) DO UPDATE SET
+                name = excluded.name, url = excluded.url, last_seen = excluded.last_seen,
+                last_run_id = excluded.last_run_id, incident_count = excluded.incident_count",
+            params![
+                jisx0402,
+                source.and_then(|s| s["name"].as_str()),
+                source.and_then(|s| s["url"].as_str()),
+                observed_at_str,
+                run_id,
+                disasters.len() as i64
+            ],
+        )?;
+
+        for disaster in disasters {
+            let Some(id) = disaster["id"].as_str() else {
+                continue;
+            };
+            let time = disaster["time"]
+                .as_str()
+                .and_then(|time| incident_datetime(time, observed_at))
+                .map(format_datetime);
+            transaction.execute(
+                "INSERT INTO incidents (id, jisx0402, time, type, category, subcategory, status, address, lat, lon, first_seen, last_seen)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?11)
+                 ON CONFLICT (id) DO UPDATE SET
+                    status = excluded.status, category = excluded.category, subcategory = excluded.subcategory,
+                    lat = excluded.lat, lon = excluded.lon, last_seen = excluded.last_seen",
+                params![
+                    id,
+                    jisx0402,
+                    time,
+                    disaster["type"].as_str(),
+                    disaster["category"].as_str(),
+                    disaster["subcategory"].as_str(),
+                    disaster["status"].as_str(),
+                    disaster["address"].as_str(),
+                    disaster["lat"].as_f64(),
+                    disaster["lon"].as_f64(),
+                    observed_at_str
+                ],
+            )?;
+            transaction.execute(
+                "INSERT OR REPLACE INTO observations (run_id, incident_id, observed_at, status) VALUES (?1, ?2, ?3, ?4)",
+                params![run_id, id, observed_at_str, disaster["status"].as_str()],
+            )?;
+            incident_count += 1;
+        }
+    }
+
+    transaction.execute(
+        "UPDATE runs SET source_count = ?1, incident_count = ?2 WHERE id = ?3",
+        params![source_count, incident_count, run_id],
+    )?;
+    transaction.commit()?;
+    Ok(run_id)
+}
+
+/// 市区町村の出動情報のうち、出動日時が`from`以上`to`未満のものを出動日時の順に返す関数
+pub fn incidents_by_municipality(
+    connection: &Connection,
+    jisx0402: &str,
+    from: DateTime<FixedOffset>,
+    to: DateTime<FixedOffset>,
+) -> Result<Vec<Value>, Box<dyn std::error::Error>> {
+    let mut statement = connection.prepare(
+        "SELECT id, jisx0402, time, type, category, subcategory, status, address, lat, lon, first_seen, last_seen
+         FROM incidents WHERE jisx0402 = ?1 AND time >= ?2 AND time < ?3 ORDER BY time, id",
+    )?;
+    let rows = statement.query_map(params![jisx0402, format_datetime(from), format_datetime(to)], |row| {
+        Ok(json!({
+            "id": row.get::<_, String>(0)?,
+            "jisx0402": row.get::<_, String>(1)?,
+            "time": row.get::<_, Option<String>>(2)?,
+            "type": row.get::<_, Option<String>>(3)?,
+            "category": row.get::<_, Option<String>>(4)?,
+            "subcategory": row.get::<_, Option<String>>(5)?,
+            "status": row.get::<_, Option<String>>(6)?,
+            "address": row.get::<_, Option<String>>(7)?,
+            "lat": row.get::<_, Option<f64>>(8)?,
+            "lon": row.get::<_, Option<f64>>(9)?,
+            "first_seen": row.get::<_, String>(10)?,
+            "last_seen": row.get::<_, String>(11)?
+        }))
+    })?;
+    Ok(rows.collect::<Result<Vec<_>, _>>()?)
+}
+
+/// 出動日時が`from`以上`to`未満の出動情報の件数を、出動種別の大分類ごとに件数の多い順で返す関数
+pub fn counts_by_category(
+    connection: &Connection,
+    from: DateTime<FixedOffset>,
+    to: DateTime<FixedOffset>,
+) -> Result<Vec<(String, i64)>, Box<dyn std::error::Error>> {
+    let mut statement = connection.prepare(
+        "SELECT COALESCE(category, 'other'), COUNT(*) FROM incidents
+         WHERE time >= ?1 AND time < ?2 GROUP BY 1 ORDER BY 2 DESC, 1",
+    )?;
+    let rows = statement.query_map(params![format_datetime(from), format_datetime(to)], |row| {
+        Ok((row.get(0)?, row.get(1)?))
+    })?;
+    Ok(rows.collect::<Result<Vec<_>, _>>()?)
+}
+
+/// ソースごとの、最後に取得できた日時と最後に出動情報を観測した日時を返す関数
+pub fn last_seen_per_source(connection: &Connection) -> Result<Vec<Value>, Box<dyn std::error::Error>> {
+    let mut statement = connection.prepare(
+        "SELECT s.jisx0402, s.name, s.url, s.last_seen, s.incident_count,
+                (SELECT MAX(i.last_seen) FROM incidents i WHERE i.jisx0402 = s.jisx0402)
+         FROM sources s ORDER BY s.jisx0402",
+    )?;
+    let rows = statement.query_map([], |row| {
+        Ok(json!({
+            "jisx0402": row.get::<_, String>(0)?,
+            "name": row.get::<_, Option<String>>(1)?,
+            "url": row.get::<_, Option<String>>(2)?,
+            "last_seen": row.get::<_, String>(3)?,
+            "incident_count": row.get::<_, i64>(4)?,
+            "last_incident_seen": row.get::<_, Option<String>>(5)?
+        }))
+    })?;
+    Ok(rows.collect::<Result<Vec<_>, _>>()?)
+}
+
+/// 最後に記録した実行の結果を返す関数
+pub fn last_run(connection: &Connection) -> Result<Option<Value>, Box<dyn std::error::Error>> {
+    Ok(connection
+        .query_row(
+            "SELECT id, observed_at, source_count, incident_count, failed_parser_count FROM runs ORDER BY id DESC LIMIT 1",
+            [],
+            |row| {
+                Ok(json!({
+                    "id": row.get::<_, i64>(0)?,
+                    "observed_at": row.get::<_, String>(1)?,
+                    "source_count": row.get::<_, i64>(2)?,
+                    "incident_count": row.get::<_, i64>(3)?,
+                    "failed_parser_count": row.get::<_, i64>(4)?
+                }))
+            },
+        )
+        .optional()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::MemorySink;
+
+    #[test]
+    fn records_only_sources_fetched_in_this_run() {
+        let ctx = RunContext::new(Box::new(MemorySink::new()));
+        crate::save_disaster_json(
+            &ctx,
+            &json!({
+                "jisx0402": "011002",
+                "source": [{ "url": "https://example.jp/", "name": "札幌市消防局" }],
+                "disasters": [{ "type": "建物火災", "address": "北海道札幌市中央区北1条西2丁目", "time": "10:00" }]
+            }),
+        )
+        .unwrap();
+        // 取得に失敗し、前回の出動情報を引き続き出力しているソース
+        ctx.write(
+            "012025.json",
+            json!({
+                "jisx0402": "012025",
+                "source": [{ "url": "https://example.jp/", "name": "函館市消防本部" }],
+                "disasters": [{ "id": "202610190900-012025", "type": "救急支援", "time": "09:00" }],
+                "fetched_at": "2026-10-19T09:00:00+09:00",
+                "status": "stale"
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        let mut connection = Connection::open_in_memory().unwrap();
+        connection.execute_batch(SCHEMA).unwrap();
+        let files = ["011002.json".to_string(), "012025.json".to_string()];
+        record_run(&ctx, &mut connection, &files, ctx.state().observed_at(), 1).unwrap();
+
+        let sources = last_seen_per_source(&connection).unwrap();
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0]["jisx0402"], json!("011002"));
+        let run = last_run(&connection).unwrap().unwrap();
+        assert_eq!((run["source_count"].clone(), run["incident_count"].clone()), (json!(1), json!(1)));
+    }
+}
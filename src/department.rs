@@ -0,0 +1,184 @@
+use serde_json::{json, Value};
+
+use crate::jisx0402;
+
+/// 消防本部の設置形態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DepartmentKind {
+    /// 市町村が単独で設置する消防本部
+    Municipal,
+    /// 複数の市町村による一部事務組合・広域連合
+    Union,
+    /// 他の市町村から消防事務の委託を受けている消防本部
+    Delegation,
+}
+
+impl DepartmentKind {
+    /// 出力に使用する識別子
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DepartmentKind::Municipal => "municipal",
+            DepartmentKind::Union => "union",
+            DepartmentKind::Delegation => "delegation",
+        }
+    }
+
+    /// 日本語の名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            DepartmentKind::Municipal => "単独",
+            DepartmentKind::Union => "一部事務組合・広域連合",
+            DepartmentKind::Delegation => "事務委託",
+        }
+    }
+}
+
+/// 消防本部と、その管轄する市町村
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Department {
+    /// 消防本部の識別子（`dist/departments/（識別子）.json`のファイル名にも使用する）
+    pub id: &'static str,
+    /// 消防本部の名称（ソースの`name`と同じ）
+    pub name: &'static str,
+    pub kind: DepartmentKind,
+    /// 管轄する市町村のうち、このクレートが対応している市町村の地方公共団体コード
+    pub members: &'static [&'static str],
+}
+
+impl Department {
+    /// 構成市町村を、地方公共団体コード表の名称とともに返す
+    pub fn members_json(&self) -> Value {
+        Value::Array(
+            self.members
+                .iter()
+                .map(|code| {
+                    let government = jisx0402::lookup(code);
+                    json!({
+                        "jisx0402": code,
+                        "name": government.map(|g| g.display_name()),
+                        "prefecture": government.map(|g| g.prefecture)
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    /// 住所が構成市町村のいずれに属するかを判定し、その地方公共団体コードを返す
+    ///
+    /// 住所は`jisx0402::attribute_municipality`で判定し、構成市町村以外と判定された場合は`None`を返す。
+    pub fn member_for_address(&self, address: &str) -> Option<&'static str> {
+        let government = jisx0402::attribute_municipality(address, self.members.first().copied())?;
+        self.members.iter().find(|code| **code == government.code).copied()
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "id": self.id,
+            "name": self.name,
+            "kind": self.kind.as_str(),
+            "members": self.members_json()
+        })
+    }
+}
+
+macro_rules! department {
+    ($id:expr, $name:expr, $kind:ident, [$($member:expr),* $(,)?]) => {
+        Department { id: $id, name: $name, kind: DepartmentKind::$kind, members: &[$($member),*] }
+    };
+}
+
+/// 対応している消防本部の一覧
+///
+/// 新たな市町村に対応した場合は、その市町村を管轄する消防本部の`members`へ地方公共団体コードを追加すること。
+pub const DEPARTMENTS: &[Department] = &[
+    department!("sapporo", "札幌市消防局", Municipal, ["011002"]),
+    department!("hakodate", "函館市消防本部", Municipal, ["012025"]),
+    department!("tomakomai", "苫小牧市消防本部", Municipal, ["012131"]),
+    department!("ebetsu", "江別市消防本部", Municipal, ["012173"]),
+    department!("chitose", "千歳市消防本部", Municipal, ["012246"]),
+    department!("eniwa", "恵庭市消防本部", Municipal, ["012319"]),
+    department!("kitahiroshima", "北広島市消防本部", Municipal, ["012343"]),
+    department!("tsugaru", "つがる市消防本部", Municipal, ["022098"]),
+    department!("sakata-chiku", "酒田地区広域行政組合消防本部", Union, ["062049", "064289", "064611"]),
+    department!("tendo", "天童市消防本部", Municipal, ["062103"]),
+    department!("koriyama-chiho", "郡山地方広域消防組合消防本部", Union, ["072036", "072117", "075213", "075221"]),
+    department!("tsuchiura", "土浦市消防本部", Municipal, ["082031"]),
+    department!("ibaraki-machi", "茨城町消防本部", Municipal, ["083020"]),
+    department!("utsunomiya", "宇都宮市消防局", Municipal, ["092011"]),
+    department!("kawaguchi", "川口市消防局", Municipal, ["112038"]),
+    department!("saitama-seibu", "埼玉西部消防局", Union, ["112089", "112097", "112151", "112259", "112429"]),
+    department!("hiki", "比企広域消防本部", Union, ["112127", "113417", "113425", "113433", "113476", "113492", "113697"]),
+    department!("sakado-tsurugashima", "坂戸・鶴ヶ島消防本部", Union, ["112399", "112411"]),
+    department!("nishiiruma", "西入間広域消防組合消防本部", Union, ["113263", "113271", "113484"]),
+    department!("chiba", "千葉市消防局", Municipal, ["121002"]),
+    department!("choshi", "銚子市消防本部", Municipal, ["122025"]),
+    department!("ichikawa", "市川市消防局", Municipal, ["122033"]),
+    department!("awa", "安房郡市広域市町村圏事務組合消防本部", Union, ["122050", "122238", "122343", "124630"]),
+    department!("kisarazu", "木更津市消防本部", Municipal, ["122068"]),
+    department!(
+        "chosei",
+        "長生郡市広域市町村圏組合消防本部",
+        Union,
+        ["122106", "124214", "124222", "124231", "124249", "124265", "124273"]
+    ),
+    department!("narita", "成田市消防本部", Delegation, ["122114", "123421"]),
+    department!("sakura-yachimata-shisui", "佐倉市八街市酒々井町消防組合消防本部", Union, ["122122", "122301", "123226"]),
+    department!("sanbu", "山武郡市広域行政組合消防本部", Union, ["122131", "122378", "122394", "124036", "124095"]),
+    department!("asahi", "旭市消防本部", Municipal, ["122157"]),
+    department!("kashiwa", "柏市消防局", Municipal, ["122173"]),
+    department!("isumi", "夷隅郡市広域市町村圏事務組合消防本部", Union, ["122181", "122386", "124419", "124435"]),
+    department!("ichihara", "市原市消防局", Municipal, ["122190"]),
+    department!("kimitsu", "君津市消防本部", Municipal, ["122254"]),
+    department!("futtsu", "富津市消防本部", Municipal, ["122262"]),
+    department!("yotsukaido", "四街道市消防本部", Municipal, ["122289"]),
+    department!("sodegaura", "袖ケ浦市消防本部", Municipal, ["122297"]),
+    department!("inzai", "印西地区消防組合消防本部", Union, ["122319", "122327"]),
+    department!("tomisato", "富里市消防本部", Municipal, ["122335"]),
+    department!("sosa-yokoshibahikari", "匝瑳市横芝光町消防組合消防本部", Union, ["122351", "124109"]),
+    department!("katori", "香取広域市町村圏事務組合消防本部", Union, ["122360", "123471", "123498"]),
+    department!("sakae", "栄町消防本部", Municipal, ["123293"]),
+    department!("yokohama", "横浜市消防局", Municipal, ["141003"]),
+    department!("kawasaki", "川崎市消防局", Municipal, ["141305"]),
+    department!("yokosuka", "横須賀市消防局", Delegation, ["142018", "142107"]),
+    department!("niigata", "新潟市消防局", Municipal, ["151009"]),
+    department!("nagaoka", "長岡市消防本部", Municipal, ["152021"]),
+    department!("tsubame-yahiko", "燕・弥彦総合事務組合消防本部", Union, ["152137", "153427"]),
+    department!("komatsu", "小松市消防本部", Municipal, ["172031"]),
+    department!("nagoya", "名古屋市消防局", Municipal, ["231002"]),
+    department!("kasugai", "春日井市消防本部", Municipal, ["232068"]),
+    department!("kyoto", "京都市消防局", Municipal, ["261009"]),
+    department!(
+        "osaka-minami",
+        "大阪南消防組合",
+        Union,
+        ["272141", "272167", "272213", "272221", "272264", "273813", "273821", "273830"]
+    ),
+    department!("higashiosaka", "東大阪市消防局", Municipal, ["272272"]),
+    department!("ono", "小野市消防本部", Municipal, ["282189"]),
+    department!("nara", "奈良市消防局", Municipal, ["292010"]),
+    department!("ikoma", "生駒市消防本部", Municipal, ["292095"]),
+    department!("matsue", "松江市消防本部", Municipal, ["322016"]),
+    department!("higashihiroshima", "東広島市消防局", Delegation, ["342033", "342122", "344311"]),
+    department!("hagi", "萩市消防本部", Delegation, ["352047", "355020"]),
+    department!("kitakyushu", "北九州市消防局", Municipal, ["401005"]),
+    department!("fukuoka", "福岡市消防局", Municipal, ["401307"]),
+    department!("kasuya-hokubu", "粕屋北部消防本部", Union, ["402231", "403458"]),
+    department!("saga-koiki", "佐賀広域消防局", Union, ["412015", "412040", "412082", "412104", "413275"]),
+];
+
+/// 識別子から消防本部を返す関数
+pub fn lookup(id: &str) -> Option<&'static Department> {
+    DEPARTMENTS.iter().find(|department| department.id == id)
+}
+
+/// 地方公共団体コードから、その市町村を管轄する消防本部を返す関数
+pub fn for_municipality(jisx0402: &str) -> Option<&'static Department> {
+    DEPARTMENTS.iter().find(|department| department.members.contains(&jisx0402))
+}
+
+/// このクレートが対応している（いずれかの消防本部の`members`に含まれる）地方公共団体コードか
+///
+/// 地方公共団体コードの訂正などで書き出さなくなったファイルが出力先に残っていても、集計には含めないために使用する。
+pub fn is_supported(jisx0402: &str) -> bool {
+    for_municipality(jisx0402).is_some()
+}
@@ -0,0 +1,99 @@
+use chrono::SecondsFormat;
+use serde_json::{json, Value};
+
+use crate::feed::now_jst;
+use crate::context::RunContext;
+
+/// 現在の出力形式の版
+pub const SCHEMA_VERSION: u64 = 2;
+
+/// 出力形式の版を指定する環境変数（`1`を指定すると、メタデータを付与しない第1版の形式で出力する）
+pub const SCHEMA_VERSION_ENV: &str = "EMERGENCY_DISPATCH_SCHEMA_VERSION";
+
+/// 出力する形式の版（環境変数`EMERGENCY_DISPATCH_SCHEMA_VERSION`、無ければ`SCHEMA_VERSION`）
+pub fn output_schema_version() -> u64 {
+    match std::env::var(SCHEMA_VERSION_ENV).ok().and_then(|version| version.trim().parse::<u64>().ok()) {
+        Some(1) => 1,
+        Some(version) if version != SCHEMA_VERSION => {
+            eprintln!("出力形式の版{}には対応していないため、第{}版で出力します", version, SCHEMA_VERSION);
+            SCHEMA_VERSION
+        }
+        _ => SCHEMA_VERSION,
+    }
+}
+
+/// 第1版の形式で出力するか
+pub fn is_compat_mode() -> bool {
+    output_schema_version() == 1
+}
+
+/// 出力するデータにメタデータを付与する関数
+///
+/// 第2版では`schema_version`・`generated_at`・`crate_version`・`fetched_at`・`parser_id`を付与し、データ本体を`data`に格納する。
+/// 第1版の形式で出力する場合は、データ本体をそのまま返す。
+pub fn wrap(data: Value, fetched_at: Option<&str>, parser_id: Option<&str>) -> Value {
+    if is_compat_mode() {
+        return data;
+    }
+    json!({
+        "schema_version": SCHEMA_VERSION,
+        "generated_at": now_jst().to_rfc3339_opts(SecondsFormat::Secs, false),
+        "crate_version": env!("CARGO_PKG_VERSION"),
+        "fetched_at": fetched_at,
+        "parser_id": parser_id,
+        "data": data
+    })
+}
+
+/// 市区町村ごとのファイルにメタデータを付与する関数
+///
+/// 第2版では取得した日時をメタデータの`fetched_at`へ移し、第1版ではデータ本体の`fetched_at`として残す。
+pub fn wrap_municipality(mut data: Value, parser_id: Option<&str>) -> Value {
+    if is_compat_mode() {
+        return data;
+    }
+    let fetched_at = data.as_object_mut().and_then(|data| data.remove("fetched_at"));
+    wrap(data, fetched_at.as_ref().and_then(Value::as_str), parser_id)
+}
+
+/// 出力されたデータからメタデータを取り除き、データ本体を返す関数（第1版の形式の場合はそのまま返す）
+///
+/// メタデータに`fetched_at`がある場合は、データ本体の`fetched_at`として戻す。
+pub fn unwrap(value: Value) -> Value {
+    if value["schema_version"].as_u64().is_none() {
+        return value;
+    }
+    let fetched_at = value["fetched_at"].clone();
+    let mut data = value["data"].clone();
+    if let Some(data) = data.as_object_mut().filter(|_| !fetched_at.is_null()) {
+        data.insert("fetched_at".to_string(), fetched_at);
+    }
+    data
+}
+
+/// 出力先のファイルを読み込み、データ本体を返す関数（ファイルが無い場合は`None`）
+pub fn read(ctx: &RunContext, path: &str) -> Result<Option<Value>, Box<dyn std::error::Error>> {
+    match ctx.read_to_string(path)? {
+        Some(data) => Ok(Some(unwrap(serde_json::from_str(&data)?))),
+        None => Ok(None),
+    }
+}
+
+/// データ本体のJSON Schemaから、出力されるファイルのJSON Schemaを生成する関数（第1版の形式の場合はそのまま返す）
+pub fn schema(data: Value) -> Value {
+    if is_compat_mode() {
+        return data;
+    }
+    json!({
+        "type": "object",
+        "required": ["schema_version", "generated_at", "crate_version", "fetched_at", "parser_id", "data"],
+        "properties": {
+            "schema_version": { "const": SCHEMA_VERSION },
+            "generated_at": { "type": "string", "format": "date-time" },
+            "crate_version": { "type": "string", "minLength": 1 },
+            "fetched_at": { "type": ["string", "null"], "format": "date-time" },
+            "parser_id": { "type": ["string", "null"] },
+            "data": data
+        }
+    })
+}
@@ -0,0 +1,491 @@
+use std::collections::HashMap;
+use std::io;
+use chrono::{DateTime, Datelike, FixedOffset, NaiveTime, SecondsFormat, Timelike, Utc};
+use quick_xml::events::{BytesDecl, BytesText, Event};
+use quick_xml::Writer;
+use serde_json::{json, Value};
+
+use crate::context::RunContext;
+use crate::envelope;
+use crate::jisx0402;
+use crate::status::IncidentStatus;
+
+/// フィードの既定のタイトル
+pub const FEED_TITLE: &str = "日本の緊急車両出動フィード（非公式） by oageo";
+
+/// フィードの既定のリンク先
+pub const FEED_LINK: &str = "https://github.com/oageo/emergency-dispatch";
+
+/// フィードの作者
+pub const FEED_AUTHOR: &str = "oageo";
+
+/// Atomの`id`に使用するURIの接頭辞（項目の`guid`やフィードの識別子の前に付与する）
+pub const ID_URI_PREFIX: &str = "urn:emergency-dispatch:";
+
+/// 各消防本部が公開している出動時刻のタイムゾーン（日本標準時）
+pub fn jst() -> FixedOffset {
+    FixedOffset::east_opt(9 * 3600).unwrap()
+}
+
+/// 日本標準時での現在日時
+pub fn now_jst() -> DateTime<FixedOffset> {
+    Utc::now().with_timezone(&jst())
+}
+
+/// フィードの1項目（出動情報1件）
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedItem {
+    /// 項目の識別子（RSSの`guid`）
+    pub guid: String,
+    /// 出動日時
+    pub time: DateTime<FixedOffset>,
+    /// 「【状況】種別（消防本部名）」形式のタイトル
+    pub title: String,
+    /// 出動種別（例: 「建物火災」）
+    pub disaster_type: String,
+    /// 出動種別の大分類の識別子（`category::Category::as_str`）
+    pub category: Option<String>,
+    /// 出動状況の識別子（`status::IncidentStatus::as_str`）
+    pub status: Option<String>,
+    /// この実行で記録されたイベントの種類（`added`・`changed`・`resolved`。前回から変化が無い場合は`None`）
+    pub event: Option<String>,
+    /// 最後に変化を観測した日時（変化が無い場合は出動日時）
+    pub updated: DateTime<FixedOffset>,
+    pub address: String,
+    pub source_name: String,
+    pub source_url: String,
+    pub jisx0402: String,
+}
+
+impl FeedItem {
+    /// Atomの`id`として使用するURI（`guid`に`urn:emergency-dispatch:`を付与したもの）
+    pub fn id_uri(&self) -> String {
+        format!("{}{}", ID_URI_PREFIX, self.guid)
+    }
+
+    /// 出動種別・大分類・地方公共団体コードの順に並べた分類
+    fn tags(&self) -> Vec<&str> {
+        let mut tags = vec![self.disaster_type.as_str()];
+        tags.extend(self.category.as_deref());
+        tags.push(&self.jisx0402);
+        tags
+    }
+}
+
+/// フィード全体の情報（RSSの`channel`）
+#[derive(Debug, Clone)]
+pub struct FeedChannel {
+    /// フィードの識別子（Atomの`id`は`urn:emergency-dispatch:`を付与したもの）
+    pub id: String,
+    pub title: String,
+    pub link: String,
+    pub description: String,
+    /// フィードの生成日時
+    pub built_at: DateTime<FixedOffset>,
+}
+
+impl FeedChannel {
+    /// 全国の出動情報をまとめたフィードの情報を返す
+    pub fn nationwide(built_at: DateTime<FixedOffset>) -> Self {
+        FeedChannel {
+            id: "all".to_string(),
+            title: FEED_TITLE.to_string(),
+            link: FEED_LINK.to_string(),
+            description: format!(
+                "全国の緊急車両出動情報を統一されたフォーマットで提供する。フィード生成日時: {}",
+                built_at.format("%Y-%m-%d %H:%M:%S %:z")
+            ),
+            built_at,
+        }
+    }
+
+    /// 1つの市区町村の出動情報のフィードの情報を返す（識別子は地方公共団体コード）
+    pub fn municipality(jisx0402: &str, built_at: DateTime<FixedOffset>) -> Self {
+        let name = jisx0402::lookup(jisx0402).map_or_else(|| jisx0402.to_string(), |government| government.full_name());
+        FeedChannel {
+            id: jisx0402.to_string(),
+            title: format!("{}の緊急車両出動フィード（非公式） by oageo", name),
+            link: FEED_LINK.to_string(),
+            description: format!(
+                "{}（地方公共団体コード: {}）の緊急車両出動情報を統一されたフォーマットで提供する。フィード生成日時: {}",
+                name,
+                jisx0402,
+                built_at.format("%Y-%m-%d %H:%M:%S %:z")
+            ),
+            built_at,
+        }
+    }
+
+    /// 1つの都道府県の出動情報のフィードの情報を返す（識別子は「pref-」と都道府県コード2桁）
+    pub fn prefecture(prefecture_code: &str, built_at: DateTime<FixedOffset>) -> Self {
+        let name = jisx0402::prefecture_of(prefecture_code)
+            .map_or_else(|| prefecture_code.to_string(), |government| government.prefecture.to_string());
+        FeedChannel {
+            id: format!("pref-{}", prefecture_code),
+            title: format!("{}の緊急車両出動フィード（非公式） by oageo", name),
+            link: FEED_LINK.to_string(),
+            description: format!(
+                "{}内の緊急車両出動情報を統一されたフォーマットで提供する。フィード生成日時: {}",
+                name,
+                built_at.format("%Y-%m-%d %H:%M:%S %:z")
+            ),
+            built_at,
+        }
+    }
+}
+
+/// 「HH:MM」形式の出動時刻に日付を補い、日本標準時の日時とする関数
+///
+/// 出動時刻は当日のものとみなし、実行時刻より10分以上未来となる場合は前日のものとする。
+pub fn incident_datetime(time_str: &str, now: DateTime<FixedOffset>) -> Option<DateTime<FixedOffset>> {
+    let parsed_time = NaiveTime::parse_from_str(time_str, "%H:%M").ok()?;
+    let now_naive = now.naive_local();
+    let mut date = now_naive.date();
+    if date.and_time(parsed_time) > now_naive + chrono::Duration::minutes(10) {
+        date = date.pred_opt().unwrap_or(date);
+    }
+    date.and_time(parsed_time).and_local_timezone(now.timezone()).single()
+}
+
+/// 市区町村ごとのJSONファイルから、フィードの項目を時刻順に並べて返す関数
+///
+/// guidには状態ファイルが割り当てた出動情報の識別子（`id`）を使用する。
+/// 識別子が無い場合は出動日時と地方公共団体コードから生成し、同じguidとなる項目が複数ある場合は2件目以降に連番を付与する。
+/// 項目の更新・終息は、`events`（`state::events`）のうち同じ識別子の`changed`・`resolved`のイベントから判定する。
+pub fn collect_items(
+    ctx: &RunContext,
+    files: &[String],
+    events: &[Value],
+    now: DateTime<FixedOffset>,
+) -> Result<Vec<FeedItem>, Box<dyn std::error::Error>> {
+    let mut items = vec![];
+
+    // 識別子ごとに、この実行で記録されたイベントの種類と観測日時を引けるようにする
+    let events = events
+        .iter()
+        .filter_map(|event| {
+            let kind = event["event"].as_str().filter(|kind| ["added", "changed", "resolved"].contains(kind))?;
+            let observed_at = event["observed_at"].as_str().and_then(|time| DateTime::parse_from_rfc3339(time).ok())?;
+            Some((event["id"].as_str()?, (kind, observed_at)))
+        })
+        .collect::<HashMap<_, _>>();
+
+    for file in files {
+        let Some(json) = envelope::read(ctx, file)? else {
+            continue;
+        };
+
+        let (Some(disasters), Some(jisx0402)) = (json["disasters"].as_array(), json["jisx0402"].as_str()) else {
+            continue;
+        };
+        let source = json["source"].as_array().and_then(|source| source.first());
+        let (Some(source_name), Some(source_url)) = (
+            source.and_then(|s| s["name"].as_str()),
+            source.and_then(|s| s["url"].as_str()),
+        ) else {
+            continue;
+        };
+
+        for disaster in disasters {
+            let (Some(time_str), Some(disaster_type), Some(address)) = (
+                disaster["time"].as_str(),
+                disaster["type"].as_str(),
+                disaster["address"].as_str(),
+            ) else {
+                continue;
+            };
+            let Some(time) = incident_datetime(time_str, now) else {
+                continue;
+            };
+
+            let guid = disaster["id"]
+                .as_str()
+                .map(str::to_string)
+                .unwrap_or_else(|| {
+                    format!(
+                        "{}{:02}{:02}{:02}{:02}-{}",
+                        time.year(),
+                        time.month(),
+                        time.day(),
+                        time.hour(),
+                        time.minute(),
+                        jisx0402
+                    )
+                });
+
+            // 鎮火・終了・誤報などの場合はタイトルの先頭に「【状況】」を、出動中のまま内容が変わった場合は「【更新】」を付与
+            let status = disaster["status"].as_str().and_then(IncidentStatus::from_str_opt);
+            let event = disaster["id"].as_str().and_then(|id| events.get(id));
+            let status_prefix = match (status, event) {
+                (Some(status), _) if status != IncidentStatus::Dispatched => format!("【{}】", status.label()),
+                (_, Some(("changed", _))) => "【更新】".to_string(),
+                _ => String::new(),
+            };
+            let updated = match event {
+                Some((kind, observed_at)) if *kind != "added" => *observed_at,
+                _ => time,
+            };
+
+            items.push(FeedItem {
+                guid,
+                time,
+                title: format!("{}{}（{}）", status_prefix, disaster_type, source_name),
+                disaster_type: disaster_type.to_string(),
+                category: disaster["category"].as_str().map(str::to_string),
+                status: status.map(|status| status.as_str().to_string()),
+                event: event.map(|(kind, _)| kind.to_string()),
+                updated,
+                address: address.to_string(),
+                source_name: source_name.to_string(),
+                source_url: source_url.to_string(),
+                jisx0402: jisx0402.to_string(),
+            });
+        }
+    }
+
+    // 時刻順に並べ、同じ時刻の項目は地方公共団体コード・住所の順とする
+    items.sort_by(|a, b| {
+        (a.time, &a.jisx0402, &a.address).cmp(&(b.time, &b.jisx0402, &b.address))
+    });
+
+    // 重複するguidを解決（シーケンス番号を付与）
+    let mut guid_counts: HashMap<String, usize> = HashMap::new();
+    for item in &mut items {
+        let count = guid_counts.entry(item.guid.clone()).or_insert(0);
+        *count += 1;
+        if *count > 1 {
+            item.guid = format!("{}-{:02}", item.guid, *count - 1);
+        }
+    }
+
+    Ok(items)
+}
+
+/// テキストのみを持つ要素を書き出す（内容はエスケープされる）
+fn write_text_element<W: io::Write>(writer: &mut Writer<W>, name: &str, text: &str) -> io::Result<()> {
+    writer.create_element(name).write_text_content(BytesText::new(text))?;
+    Ok(())
+}
+
+/// RSS 2.0形式のフィードを生成する関数
+///
+/// 日時はRFC 822形式（例: `Mon, 19 Oct 2026 12:34:00 +0900`）で出力する。
+/// 各項目には出動種別、大分類（`domain="category"`）、地方公共団体コード（`domain="jisx0402"`）を`category`要素として付与する。
+pub fn write_rss(channel: &FeedChannel, items: &[FeedItem]) -> io::Result<Vec<u8>> {
+    let mut writer = Writer::new_with_indent(Vec::new(), b' ', 2);
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    writer
+        .create_element("rss")
+        .with_attribute(("version", "2.0"))
+        .write_inner_content(|writer| {
+            writer.create_element("channel").write_inner_content(|writer| {
+                write_text_element(writer, "title", &channel.title)?;
+                write_text_element(writer, "link", &channel.link)?;
+                write_text_element(writer, "description", &channel.description)?;
+                write_text_element(writer, "lastBuildDate", &channel.built_at.to_rfc2822())?;
+                write_text_element(writer, "generator", "emergency-dispatch")?;
+                write_text_element(writer, "language", "ja")?;
+
+                for item in items {
+                    writer.create_element("item").write_inner_content(|writer| {
+                        write_text_element(writer, "title", &item.title)?;
+                        write_text_element(writer, "description", &item.address)?;
+                        write_text_element(writer, "link", &item.source_url)?;
+                        write_text_element(writer, "pubDate", &item.time.to_rfc2822())?;
+                        writer
+                            .create_element("guid")
+                            .with_attribute(("isPermaLink", "false"))
+                            .write_text_content(BytesText::new(&item.guid))?;
+                        write_text_element(writer, "category", &item.disaster_type)?;
+                        if let Some(category) = &item.category {
+                            writer
+                                .create_element("category")
+                                .with_attribute(("domain", "category"))
+                                .write_text_content(BytesText::new(category))?;
+                        }
+                        writer
+                            .create_element("category")
+                            .with_attribute(("domain", "jisx0402"))
+                            .write_text_content(BytesText::new(&item.jisx0402))?;
+                        Ok(())
+                    })?;
+                }
+                Ok(())
+            })?;
+            Ok(())
+        })?;
+
+    let mut rss_feed = writer.into_inner();
+    rss_feed.push(b'\n');
+    Ok(rss_feed)
+}
+
+/// 分類を`category`要素として書き出す（出動種別には分類の体系を付与しない）
+fn write_atom_categories<W: io::Write>(writer: &mut Writer<W>, item: &FeedItem) -> io::Result<()> {
+    writer.create_element("category").with_attribute(("term", item.disaster_type.as_str())).write_empty()?;
+    if let Some(category) = &item.category {
+        writer
+            .create_element("category")
+            .with_attributes([("term", category.as_str()), ("scheme", "category")])
+            .write_empty()?;
+    }
+    writer
+        .create_element("category")
+        .with_attributes([("term", item.jisx0402.as_str()), ("scheme", "jisx0402")])
+        .write_empty()?;
+    Ok(())
+}
+
+/// Atom 1.0形式のフィードを生成する関数
+///
+/// 項目の`id`はRSSの`guid`に`urn:emergency-dispatch:`を付与したURIとし、形式が異なっても同じ出動情報を同じ識別子で表す。
+pub fn write_atom(channel: &FeedChannel, items: &[FeedItem]) -> io::Result<Vec<u8>> {
+    let mut writer = Writer::new_with_indent(Vec::new(), b' ', 2);
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    writer
+        .create_element("feed")
+        .with_attributes([("xmlns", "http://www.w3.org/2005/Atom"), ("xml:lang", "ja")])
+        .write_inner_content(|writer| {
+            write_text_element(writer, "id", &format!("{}{}", ID_URI_PREFIX, channel.id))?;
+            write_text_element(writer, "title", &channel.title)?;
+            write_text_element(writer, "subtitle", &channel.description)?;
+            write_text_element(writer, "updated", &channel.built_at.to_rfc3339_opts(SecondsFormat::Secs, false))?;
+            writer
+                .create_element("link")
+                .with_attributes([("rel", "alternate"), ("href", channel.link.as_str())])
+                .write_empty()?;
+            writer.create_element("author").write_inner_content(|writer| {
+                write_text_element(writer, "name", FEED_AUTHOR)
+            })?;
+            write_text_element(writer, "generator", "emergency-dispatch")?;
+
+            for item in items {
+                writer.create_element("entry").write_inner_content(|writer| {
+                    write_text_element(writer, "id", &item.id_uri())?;
+                    write_text_element(writer, "title", &item.title)?;
+                    write_text_element(writer, "summary", &item.address)?;
+                    write_text_element(writer, "published", &item.time.to_rfc3339_opts(SecondsFormat::Secs, false))?;
+                    write_text_element(writer, "updated", &item.updated.to_rfc3339_opts(SecondsFormat::Secs, false))?;
+                    writer
+                        .create_element("link")
+                        .with_attributes([("rel", "alternate"), ("href", item.source_url.as_str())])
+                        .write_empty()?;
+                    writer.create_element("author").write_inner_content(|writer| {
+                        write_text_element(writer, "name", &item.source_name)?;
+                        write_text_element(writer, "uri", &item.source_url)
+                    })?;
+                    write_atom_categories(writer, item)
+                })?;
+            }
+            Ok(())
+        })?;
+
+    let mut atom_feed = writer.into_inner();
+    atom_feed.push(b'\n');
+    Ok(atom_feed)
+}
+
+/// JSON Feed 1.1形式のフィードを生成する関数
+///
+/// 項目の`id`はRSSの`guid`と同じ値とする。地方公共団体コードなどの構造化された情報は拡張の`_emergency_dispatch`に格納する。
+pub fn write_json_feed(channel: &FeedChannel, items: &[FeedItem]) -> Value {
+    let items = items
+        .iter()
+        .map(|item| {
+            json!({
+                "id": item.guid,
+                "url": item.source_url,
+                "title": item.title,
+                "content_text": item.address,
+                "date_published": item.time.to_rfc3339_opts(SecondsFormat::Secs, false),
+                "date_modified": item.updated.to_rfc3339_opts(SecondsFormat::Secs, false),
+                "authors": [{ "name": item.source_name, "url": item.source_url }],
+                "tags": item.tags(),
+                "_emergency_dispatch": {
+                    "jisx0402": item.jisx0402,
+                    "type": item.disaster_type,
+                    "category": item.category,
+                    "status": item.status,
+                    "event": item.event,
+                    "address": item.address
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "version": "https://jsonfeed.org/version/1.1",
+        "title": channel.title,
+        "home_page_url": channel.link,
+        "description": channel.description,
+        "language": "ja",
+        "authors": [{ "name": FEED_AUTHOR }],
+        "items": items
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn item() -> FeedItem {
+        let time = jst().with_ymd_and_hms(2026, 10, 19, 10, 0, 0).unwrap();
+        FeedItem {
+            guid: "202610191000-011002".to_string(),
+            time,
+            title: "危険物<漏洩>&火災（札幌市消防局）".to_string(),
+            disaster_type: "危険物<漏洩>&火災".to_string(),
+            category: Some("hazmat".to_string()),
+            status: Some("dispatched".to_string()),
+            event: Some("added".to_string()),
+            updated: time,
+            address: "北海道札幌市中央区北1条西2丁目 A&B<ビル>".to_string(),
+            source_name: "札幌市消防局".to_string(),
+            source_url: "https://example.jp/?a=1&b=2".to_string(),
+            jisx0402: "011002".to_string(),
+        }
+    }
+
+    fn channel() -> FeedChannel {
+        FeedChannel::nationwide(jst().with_ymd_and_hms(2026, 10, 19, 12, 34, 0).unwrap())
+    }
+
+    #[test]
+    fn escapes_markup_in_rss_and_atom() {
+        for feed in [write_rss(&channel(), &[item()]).unwrap(), write_atom(&channel(), &[item()]).unwrap()] {
+            let feed = String::from_utf8(feed).unwrap();
+            assert!(feed.contains("北海道札幌市中央区北1条西2丁目 A&amp;B&lt;ビル&gt;"), "{}", feed);
+            assert!(feed.contains("危険物&lt;漏洩&gt;&amp;火災"), "{}", feed);
+            assert!(feed.contains("https://example.jp/?a=1&amp;b=2"), "{}", feed);
+            assert!(!feed.contains("<ビル>") && !feed.contains("<漏洩>"), "{}", feed);
+        }
+    }
+
+    #[test]
+    fn writes_rss_dates_in_rfc_822_with_jst() {
+        let rss = String::from_utf8(write_rss(&channel(), &[item()]).unwrap()).unwrap();
+        assert!(rss.contains("<pubDate>Mon, 19 Oct 2026 10:00:00 +0900</pubDate>"), "{}", rss);
+        assert!(rss.contains("<lastBuildDate>Mon, 19 Oct 2026 12:34:00 +0900</lastBuildDate>"), "{}", rss);
+    }
+
+    #[test]
+    fn completes_the_date_in_jst_from_the_incident_time() {
+        let now = jst().with_ymd_and_hms(2026, 10, 19, 0, 5, 0).unwrap();
+        // 実行時刻より10分以上未来の時刻は前日のものとする
+        assert_eq!(incident_datetime("23:50", now), jst().with_ymd_and_hms(2026, 10, 18, 23, 50, 0).single());
+        assert_eq!(incident_datetime("00:12", now), jst().with_ymd_and_hms(2026, 10, 19, 0, 12, 0).single());
+        assert_eq!(incident_datetime("24:00", now), None);
+    }
+
+    #[test]
+    fn keeps_structured_fields_in_the_json_feed() {
+        let feed = write_json_feed(&channel(), &[item()]);
+        let entry = &feed["items"][0];
+        assert_eq!(entry["id"], json!("202610191000-011002"));
+        assert_eq!(entry["date_published"], json!("2026-10-19T10:00:00+09:00"));
+        assert_eq!(entry["_emergency_dispatch"]["address"], json!("北海道札幌市中央区北1条西2丁目 A&B<ビル>"));
+        assert_eq!(entry["tags"], json!(["危険物<漏洩>&火災", "hazmat", "011002"]));
+    }
+}
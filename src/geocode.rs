@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use encoding_rs::SHIFT_JIS;
+
+use crate::address::{normalize_address, ParsedAddress};
+
+/// 地名辞書ファイルの既定の配置場所
+pub const DEFAULT_GAZETTEER_PATH: &str = "data/gazetteer";
+
+/// 地名辞書ファイルの場所を指定する環境変数
+pub const GAZETTEER_PATH_ENV: &str = "EMERGENCY_DISPATCH_GAZETTEER";
+
+/// ジオコーディング結果の精度
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Precision {
+    /// 市区町村の代表点
+    Municipality,
+    /// 町名・大字の代表点
+    Town,
+    /// 丁目の代表点
+    Chome,
+}
+
+impl Precision {
+    /// 全ての精度
+    pub const ALL: [Precision; 3] = [Precision::Municipality, Precision::Town, Precision::Chome];
+
+    /// 出力に使用する識別子
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Precision::Municipality => "municipality",
+            Precision::Town => "town",
+            Precision::Chome => "chome",
+        }
+    }
+}
+
+/// ジオコーディング結果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geocode {
+    pub lat: f64,
+    pub lon: f64,
+    pub precision: Precision,
+}
+
+/// 地名辞書の1行（大字・町丁目1件）
+#[derive(Debug, Clone)]
+struct GazetteerEntry {
+    /// 市区町村コード（5桁）
+    municipality_code: String,
+    /// 町名（正規化済み、「大字」「字」を除く）
+    town: String,
+    lat: f64,
+    lon: f64,
+}
+
+/// 町丁目単位の地名辞書
+///
+/// 国土交通省「位置参照情報」の大字・町丁目レベルのCSV形式
+/// （都道府県コード,都道府県名,市区町村コード,市区町村名,大字町丁目コード,大字町丁目名,緯度,経度,…）を読み込む。
+#[derive(Debug, Default)]
+pub struct Gazetteer {
+    entries: Vec<GazetteerEntry>,
+    /// 都道府県名と市区町村名（政令指定都市は「北海道札幌市中央区」のように区名まで）から`entries`の添字への対応
+    by_municipality: HashMap<String, Vec<usize>>,
+}
+
+lazy_static::lazy_static! {
+    /// プロセス内で共有する地名辞書（初回の使用時に読み込む）
+    static ref GAZETTEER: Gazetteer = Gazetteer::load_default();
+}
+
+/// 「大字」「字」を取り除いた町名を返す
+fn strip_aza(town: &str) -> &str {
+    town.trim_start_matches("大字").trim_start_matches('字')
+}
+
+/// UTF-8として読めない場合はShift_JISとして読み込む
+fn read_text(path: &Path) -> std::io::Result<String> {
+    let bytes = fs::read(path)?;
+    Ok(match String::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(e) => SHIFT_JIS.decode(e.as_bytes()).0.into_owned(),
+    })
+}
+
+/// 座標を平均して代表点を求める
+fn centroid<'a>(entries: impl Iterator<Item = &'a GazetteerEntry>) -> Option<(f64, f64)> {
+    let (count, lat, lon) = entries.fold((0usize, 0.0, 0.0), |(count, lat, lon), entry| {
+        (count + 1, lat + entry.lat, lon + entry.lon)
+    });
+    if count == 0 { None } else { Some((lat / count as f64, lon / count as f64)) }
+}
+
+impl Gazetteer {
+    /// 環境変数`EMERGENCY_DISPATCH_GAZETTEER`、無ければ`data/gazetteer`から地名辞書を読み込む
+    ///
+    /// ファイルが存在しない場合は空の辞書となり、ジオコーディングは行われない。
+    pub fn load_default() -> Self {
+        let path = std::env::var(GAZETTEER_PATH_ENV).unwrap_or_else(|_| DEFAULT_GAZETTEER_PATH.to_string());
+        match Self::load(Path::new(&path)) {
+            Ok(gazetteer) => {
+                println!("地名辞書を読み込みました: {}（{}件）", path, gazetteer.len());
+                gazetteer
+            }
+            Err(e) => {
+                eprintln!("地名辞書を読み込めなかったため、ジオコーディングを行いません: {}: {}", path, e);
+                Self::default()
+            }
+        }
+    }
+
+    /// CSVファイル、またはCSVファイルを含むディレクトリから地名辞書を読み込む
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut gazetteer = Self::default();
+        if path.is_dir() {
+            let mut files = fs::read_dir(path)?
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("csv")))
+                .collect::<Vec<_>>();
+            files.sort();
+            for file in files {
+                gazetteer.add_csv(&read_text(&file)?);
+            }
+        } else {
+            gazetteer.add_csv(&read_text(path)?);
+        }
+        Ok(gazetteer)
+    }
+
+    /// 位置参照情報形式のCSVを追加する（見出し行や解釈できない行は読み飛ばす）
+    pub fn add_csv(&mut self, csv: &str) {
+        for line in csv.lines() {
+            let fields = line.split(',').map(|field| field.trim().trim_matches('"')).collect::<Vec<_>>();
+            if fields.len() < 8 {
+                continue;
+            }
+            let (Ok(lat), Ok(lon)) = (fields[6].parse::<f64>(), fields[7].parse::<f64>()) else {
+                continue;
+            };
+            let index = self.entries.len();
+            self.entries.push(GazetteerEntry {
+                municipality_code: fields[2].to_string(),
+                town: strip_aza(&normalize_address(fields[5])).to_string(),
+                lat,
+                lon,
+            });
+            self.by_municipality
+                .entry(normalize_address(&format!("{}{}", fields[1], fields[3])))
+                .or_default()
+                .push(index);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 都道府県名と市区町村名に一致する辞書の行を返す（政令指定都市で区が不明な場合は全区の行を返す）
+    fn municipality_entries(&self, name: &str) -> Vec<&GazetteerEntry> {
+        let indices = match self.by_municipality.get(name) {
+            Some(indices) => indices.clone(),
+            None => self
+                .by_municipality
+                .iter()
+                .filter(|(municipality, _)| municipality.starts_with(name))
+                .flat_map(|(_, indices)| indices.iter().copied())
+                .collect(),
+        };
+        indices.into_iter().map(|index| &self.entries[index]).collect()
+    }
+
+    /// 地方公共団体コードに一致する辞書の行を返す
+    ///
+    /// 政令指定都市のコード（例: 札幌市 011002）の場合は、その区（01101〜）の行も含める。
+    fn code_entries(&self, jisx0402: &str) -> Vec<&GazetteerEntry> {
+        let Some(code) = jisx0402.get(..5).and_then(|code| code.parse::<u32>().ok()) else {
+            return vec![];
+        };
+        let is_designated_city = code % 1000 >= 100 && code % 1000 < 200 && code % 10 == 0;
+        self.entries
+            .iter()
+            .filter(|entry| match entry.municipality_code.parse::<u32>() {
+                Ok(entry_code) if is_designated_city => entry_code >= code && entry_code < code + 30,
+                Ok(entry_code) => entry_code == code,
+                Err(_) => false,
+            })
+            .collect()
+    }
+
+    /// 分解済みの住所から座標を求める関数
+    ///
+    /// 丁目まで一致すれば`Chome`、町名まで一致すれば`Town`、市区町村しか分からなければ`Municipality`の精度となる。
+    pub fn geocode(&self, parsed: &ParsedAddress, jisx0402: &str) -> Option<Geocode> {
+        // 同名の市区町村（府中市・伊達市など）を区別するため、都道府県名と合わせて照合する
+        let prefecture = parsed.prefecture.as_deref().unwrap_or_default();
+        let entries = match (&parsed.municipality, &parsed.ward) {
+            (Some(municipality), Some(ward)) => {
+                self.municipality_entries(&format!("{}{}{}", prefecture, municipality, ward))
+            }
+            (Some(municipality), None) => self.municipality_entries(&format!("{}{}", prefecture, municipality)),
+            (None, _) => vec![],
+        };
+        let entries = if entries.is_empty() { self.code_entries(jisx0402) } else { entries };
+
+        if let Some(town) = parsed.town.as_deref().map(strip_aza).filter(|town| !town.is_empty()) {
+            if let Some(chome) = parsed.chome {
+                let name = format!("{}{}丁目", town, chome);
+                if let Some(entry) = entries.iter().find(|entry| entry.town == name) {
+                    return Some(Geocode { lat: entry.lat, lon: entry.lon, precision: Precision::Chome });
+                }
+            }
+            let exact = centroid(entries.iter().copied().filter(|entry| entry.town == town));
+            let prefixed = || centroid(entries.iter().copied().filter(|entry| entry.town.starts_with(town)));
+            if let Some((lat, lon)) = exact.or_else(prefixed) {
+                return Some(Geocode { lat, lon, precision: Precision::Town });
+            }
+        }
+
+        centroid(entries.into_iter()).map(|(lat, lon)| Geocode { lat, lon, precision: Precision::Municipality })
+    }
+
+    /// 地方公共団体コードから市区町村の代表点を求める関数
+    pub fn municipality_centroid(&self, jisx0402: &str) -> Option<(f64, f64)> {
+        centroid(self.code_entries(jisx0402).into_iter())
+    }
+}
+
+/// 既定の地名辞書を使って分解済みの住所から座標を求める関数
+pub fn geocode(parsed: &ParsedAddress, jisx0402: &str) -> Option<Geocode> {
+    GAZETTEER.geocode(parsed, jisx0402)
+}
+
+/// 既定の地名辞書を使って地方公共団体コードから市区町村の代表点を求める関数
+pub fn municipality_centroid(jisx0402: &str) -> Option<(f64, f64)> {
+    GAZETTEER.municipality_centroid(jisx0402)
+}
@@ -0,0 +1,269 @@
+use std::cell::Cell;
+use std::time::Instant;
+use chrono::{DateTime, Duration, FixedOffset, SecondsFormat};
+use serde_json::{json, Value};
+
+use crate::department;
+use crate::feed::now_jst;
+
+/// 取得に失敗したソースの出動情報を引き続き出力する時間（分）の既定値
+pub const DEFAULT_STALE_TTL_MINUTES: i64 = 60;
+
+/// 取得に失敗したソースの出動情報を引き続き出力する時間（分）を指定する環境変数
+pub const STALE_TTL_ENV: &str = "EMERGENCY_DISPATCH_STALE_TTL";
+
+/// 取得に失敗したソースの出動情報を引き続き出力する時間（環境変数`EMERGENCY_DISPATCH_STALE_TTL`、無ければ60分）
+pub fn stale_ttl() -> Duration {
+    let minutes = std::env::var(STALE_TTL_ENV)
+        .ok()
+        .and_then(|minutes| minutes.trim().parse::<i64>().ok())
+        .unwrap_or(DEFAULT_STALE_TTL_MINUTES);
+    Duration::minutes(minutes.max(0))
+}
+
+/// 市区町村ごとのファイルの出動情報の鮮度
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SourceStatus {
+    /// 今回の実行で取得した出動情報
+    Ok,
+    /// 今回の取得に失敗したため、前回取得した出動情報を引き続き出力している
+    Stale,
+    /// 取得に失敗してから時間が経ったため、出動情報を出力していない
+    Unavailable,
+}
+
+impl SourceStatus {
+    /// 全ての鮮度
+    pub const ALL: [SourceStatus; 3] = [SourceStatus::Ok, SourceStatus::Stale, SourceStatus::Unavailable];
+
+    /// 出力に使用する識別子
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SourceStatus::Ok => "ok",
+            SourceStatus::Stale => "stale",
+            SourceStatus::Unavailable => "unavailable",
+        }
+    }
+
+    /// 取得に失敗したソースの鮮度を、最後に取得した日時から判定する関数（取得した日時が不明な場合は`Unavailable`）
+    pub fn for_failed(fetched_at: Option<DateTime<FixedOffset>>, now: DateTime<FixedOffset>, ttl: Duration) -> Self {
+        match fetched_at {
+            Some(fetched_at) if now - fetched_at <= ttl => SourceStatus::Stale,
+            _ => SourceStatus::Unavailable,
+        }
+    }
+}
+
+/// パーサー1回分の実行結果
+#[derive(Debug, Clone)]
+pub struct ParserAttempt {
+    /// パーサーの関数名（例: `return_011002`）
+    pub parser: String,
+    /// パーサーが出力する地方公共団体コード
+    pub codes: Vec<String>,
+    /// 実行を開始した日時
+    pub started_at: DateTime<FixedOffset>,
+    /// 実行にかかった時間（ミリ秒）
+    pub duration_ms: u128,
+    /// 最後に取得したページのHTTPステータスコード（取得前に失敗した場合は`None`）
+    pub http_status: Option<u16>,
+    /// 失敗した場合のエラーの種類
+    pub error_kind: Option<&'static str>,
+    /// 失敗した場合のエラーの内容
+    pub error_message: Option<String>,
+    /// パーサーは成功したが、書き出しに失敗した構成市町村
+    pub source_errors: Vec<SourceError>,
+}
+
+/// 構成市町村ごとの書き出しの失敗（JSON Schemaに合わない場合など）
+#[derive(Debug, Clone)]
+pub struct SourceError {
+    /// 地方公共団体コード
+    pub code: String,
+    /// エラーの種類
+    pub error_kind: &'static str,
+    /// エラーの内容
+    pub error_message: String,
+}
+
+impl ParserAttempt {
+    /// 地方公共団体コードについてのエラーの種類と内容（パーサー全体の失敗、無ければ構成市町村ごとの失敗）
+    pub fn error_for(&self, code: &str) -> Option<(&'static str, &str)> {
+        if let (Some(kind), Some(message)) = (self.error_kind, self.error_message.as_deref()) {
+            return Some((kind, message));
+        }
+        self.source_errors
+            .iter()
+            .find(|error| error.code == code)
+            .map(|error| (error.error_kind, error.error_message.as_str()))
+    }
+}
+
+/// パーサーの実行開始時の情報
+pub struct AttemptStart {
+    started_at: DateTime<FixedOffset>,
+    instant: Instant,
+}
+
+thread_local! {
+    /// このスレッドで最後に取得したページのHTTPステータスコード（キャッシュから取得した場合を含む）
+    ///
+    /// ページの取得は`RunContext`を受け取らないため、パーサーを実行しているスレッドごとに保持する。
+    static LAST_HTTP_STATUS: Cell<Option<u16>> = const { Cell::new(None) };
+}
+
+/// ページを取得した際のHTTPステータスコードを記録する関数
+pub fn record_http_status(status: u16) {
+    LAST_HTTP_STATUS.with(|last| last.set(Some(status)));
+}
+
+/// 1回の実行でのパーサーの実行結果（`RunContext::health`で参照する）
+#[derive(Debug, Default)]
+pub struct RunHealth {
+    /// 実行中のパーサーの関数名
+    current_parser: Option<String>,
+    /// 実行中のパーサーの、構成市町村ごとの書き出しの失敗
+    source_errors: Vec<SourceError>,
+    /// この実行でのパーサーの実行結果
+    attempts: Vec<ParserAttempt>,
+}
+
+impl RunHealth {
+    /// パーサーの実行を開始する
+    pub fn begin(&mut self, parser: &str) -> AttemptStart {
+        LAST_HTTP_STATUS.with(|last| last.set(None));
+        self.current_parser = Some(parser.trim_end_matches("()").to_string());
+        self.source_errors.clear();
+        AttemptStart { started_at: now_jst(), instant: Instant::now() }
+    }
+
+    /// 実行中のパーサーの関数名（パーサーの実行中でない場合は`None`）
+    pub fn current_parser(&self) -> Option<&str> {
+        self.current_parser.as_deref()
+    }
+
+    /// 構成市町村の書き出しの失敗を、パーサー全体ではなくその地方公共団体コードについて記録する
+    pub fn fail_source(&mut self, code: &str, error: &(dyn std::error::Error + 'static)) {
+        self.source_errors.push(SourceError {
+            code: code.to_string(),
+            error_kind: error_kind(error),
+            error_message: error.to_string(),
+        });
+    }
+
+    /// パーサーの実行結果を記録する
+    pub fn finish(&mut self, parser: &str, start: AttemptStart, result: &Result<(), Box<dyn std::error::Error>>) {
+        let parser = parser.trim_end_matches("()").to_string();
+        self.current_parser = None;
+        let (error_kind, error_message) = match result {
+            Ok(()) => (None, None),
+            Err(e) => (Some(error_kind(e.as_ref())), Some(e.to_string())),
+        };
+        self.attempts.push(ParserAttempt {
+            codes: parser_codes(&parser),
+            parser,
+            started_at: start.started_at,
+            duration_ms: start.instant.elapsed().as_millis(),
+            http_status: LAST_HTTP_STATUS.with(Cell::get),
+            error_kind,
+            error_message,
+            source_errors: std::mem::take(&mut self.source_errors),
+        });
+    }
+
+    /// この実行で取得に失敗した地方公共団体コード
+    pub fn failed_codes(&self) -> Vec<String> {
+        self.attempts
+            .iter()
+            .flat_map(|attempt| attempt.codes.iter().filter(|code| attempt.error_for(code).is_some()).cloned())
+            .collect()
+    }
+
+    /// この実行でのパーサーの実行結果
+    pub fn attempts(&self) -> &[ParserAttempt] {
+        &self.attempts
+    }
+}
+
+/// パーサーの関数名から、そのパーサーが出力する地方公共団体コードを求める関数
+///
+/// `return_011002`のように地方公共団体コードを含む場合はそのコード、
+/// `return_koriyama_chiho`のように消防本部の識別子を含む場合はその構成市町村のコードとなる。
+pub fn parser_codes(parser: &str) -> Vec<String> {
+    let name = parser.trim_end_matches("()").trim_start_matches("return_");
+    if name.len() == 6 && name.chars().all(|c| c.is_ascii_digit()) {
+        return vec![name.to_string()];
+    }
+    department::lookup(&name.replace('_', "-"))
+        .map(|department| department.members.iter().map(|code| code.to_string()).collect())
+        .unwrap_or_default()
+}
+
+/// エラーの種類の識別子
+pub const ERROR_KINDS: [&str; 8] = ["timeout", "connect", "body", "http", "io", "json", "schema", "parse"];
+
+/// エラーの種類を判定する関数
+pub fn error_kind(error: &(dyn std::error::Error + 'static)) -> &'static str {
+    if let Some(error) = error.downcast_ref::<reqwest::Error>() {
+        if error.is_timeout() {
+            "timeout"
+        } else if error.is_connect() {
+            "connect"
+        } else if error.is_decode() || error.is_body() {
+            "body"
+        } else {
+            // `error_for_status`による4xx・5xxの応答を含む
+            "http"
+        }
+    } else if error.is::<std::io::Error>() {
+        "io"
+    } else if error.is::<serde_json::Error>() {
+        "json"
+    } else if error.is::<crate::schema::SchemaError>() {
+        "schema"
+    } else {
+        "parse"
+    }
+}
+
+/// 地方公共団体コードごとのソースの状態を生成する関数
+///
+/// 最後に成功した日時と連続して失敗した回数は、前回の状態（`previous`）から引き継ぐ。
+/// `incident_count`は、成功した地方公共団体コードの出動情報の件数を返す関数である。
+pub fn source_status(
+    attempts: &[ParserAttempt],
+    previous: &Value,
+    incident_count: impl Fn(&str) -> Option<usize>,
+) -> serde_json::Map<String, Value> {
+    let mut sources = serde_json::Map::new();
+    for attempt in attempts {
+        let started_at = attempt.started_at.to_rfc3339_opts(SecondsFormat::Secs, false);
+        for code in &attempt.codes {
+            let error = attempt.error_for(code);
+            let succeeded = error.is_none();
+            let previous = &previous[code.as_str()];
+            let consecutive_failures = if succeeded {
+                0
+            } else {
+                previous["consecutive_failures"].as_u64().unwrap_or(0) + 1
+            };
+            sources.insert(
+                code.clone(),
+                json!({
+                    "ok": succeeded,
+                    "parser": attempt.parser,
+                    "department": department::for_municipality(code).map(|d| d.id),
+                    "last_attempt": started_at,
+                    "last_success": if succeeded { json!(started_at) } else { previous["last_success"].clone() },
+                    "http_status": attempt.http_status,
+                    "duration_ms": attempt.duration_ms as u64,
+                    "incident_count": if succeeded { json!(incident_count(code)) } else { Value::Null },
+                    "error_kind": error.map(|(kind, _)| kind),
+                    "error_message": error.map(|(_, message)| message),
+                    "consecutive_failures": consecutive_failures
+                }),
+            );
+        }
+    }
+    sources
+}
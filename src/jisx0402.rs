@@ -0,0 +1,183 @@
+use crate::address::{normalize_address, parse_address};
+
+/// 地方公共団体コードの一覧（総務省「全国地方公共団体コード」の都道府県・市区町村の表と同じ列構成のCSV）
+const MASTER_CSV: &str = include_str!("../data/jisx0402.csv");
+
+/// 地方公共団体コード表の1行
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalGovernment {
+    /// 6桁の地方公共団体コード（検査数字を含む）
+    pub code: &'static str,
+    /// 都道府県名
+    pub prefecture: &'static str,
+    /// 市区町村名（都道府県の行では空文字列）
+    pub name: &'static str,
+    /// 都道府県名の読み（カタカナ）
+    pub prefecture_kana: &'static str,
+    /// 市区町村名の読み（カタカナ。都道府県の行では空文字列）
+    pub name_kana: &'static str,
+}
+
+impl LocalGovernment {
+    /// 都道府県を表す行か
+    pub fn is_prefecture(&self) -> bool {
+        self.name.is_empty()
+    }
+
+    /// 政令指定都市の区を表す行か（例: 「札幌市中央区」）
+    pub fn is_ward(&self) -> bool {
+        self.name.contains('市') && self.name.ends_with('区')
+    }
+
+    /// 都道府県名を含めた名称（例: 「北海道札幌市」）
+    pub fn full_name(&self) -> String {
+        format!("{}{}", self.prefecture, self.name)
+    }
+
+    /// 市区町村名、都道府県の行では都道府県名を返す
+    pub fn display_name(&self) -> &'static str {
+        if self.is_prefecture() { self.prefecture } else { self.name }
+    }
+
+    /// 読み、都道府県の行では都道府県名の読みを返す
+    pub fn display_kana(&self) -> &'static str {
+        if self.is_prefecture() { self.prefecture_kana } else { self.name_kana }
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref MASTER: Vec<LocalGovernment> = load_master();
+}
+
+fn load_master() -> Vec<LocalGovernment> {
+    MASTER_CSV
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields = line.split(',').map(str::trim).collect::<Vec<_>>();
+            if fields.len() < 5 {
+                return None;
+            }
+            // 表に誤りがあれば取り込まず、実行時に気付けるようにする
+            if !is_valid_code(fields[0]) {
+                eprintln!("地方公共団体コード表の検査数字が正しくありません: {}", line);
+                return None;
+            }
+            Some(LocalGovernment {
+                code: fields[0],
+                prefecture: fields[1],
+                name: fields[2],
+                prefecture_kana: fields[3],
+                name_kana: fields[4],
+            })
+        })
+        .collect()
+}
+
+/// 5桁の地方公共団体コードから検査数字を求める関数
+///
+/// 上位から順に6・5・4・3・2を掛けた和を11で割った余りを11から引き、その1の位を検査数字とする。
+pub fn check_digit(code: &str) -> Option<u32> {
+    if code.len() != 5 {
+        return None;
+    }
+    let sum = code
+        .chars()
+        .zip([6, 5, 4, 3, 2])
+        .try_fold(0, |sum, (c, weight)| c.to_digit(10).map(|d| sum + d * weight))?;
+    Some((11 - sum % 11) % 10)
+}
+
+/// 6桁の地方公共団体コードの形式と検査数字が正しいかを確認する関数
+pub fn is_valid_code(code: &str) -> bool {
+    if code.len() != 6 || !code.is_ascii() {
+        return false;
+    }
+    let (body, digit) = code.split_at(5);
+    check_digit(body).is_some_and(|expected| digit.parse::<u32>().ok() == Some(expected))
+}
+
+/// 地方公共団体コード表の全ての行を返す
+pub fn all() -> &'static [LocalGovernment] {
+    &MASTER
+}
+
+/// 地方公共団体コードから表の行を返す関数
+pub fn lookup(code: &str) -> Option<&'static LocalGovernment> {
+    MASTER.iter().find(|government| government.code == code)
+}
+
+/// 地方公共団体コードの上2桁から、その都道府県の行を返す関数
+pub fn prefecture_of(code: &str) -> Option<&'static LocalGovernment> {
+    let prefecture_code = code.get(..2)?;
+    MASTER
+        .iter()
+        .find(|government| government.is_prefecture() && government.code.starts_with(prefecture_code))
+}
+
+/// 都道府県内の市区町村のうち、住所の先頭に一致する最も長い名称を返す関数
+///
+/// 「郡山市」「大和郡山市」のように、単純な区切りでは分割を誤る名称を表に基づいて取り出すために使う。
+/// 政令指定都市の区の行は対象とせず、市の行を返す。
+pub fn match_municipality(prefecture: Option<&str>, address: &str) -> Option<&'static LocalGovernment> {
+    MASTER
+        .iter()
+        .filter(|government| !government.is_prefecture() && !government.is_ward())
+        .filter(|government| prefecture.is_none_or(|prefecture| government.prefecture == prefecture))
+        .filter(|government| address.starts_with(government.name))
+        .max_by_key(|government| government.name.len())
+}
+
+/// 地方公共団体コードを検査し、問題があれば内容を返す関数
+///
+/// 検査数字の誤りはエラーとし、表に無いだけの場合は警告として扱えるよう`Ok(None)`を返す。
+pub fn verify_code(code: &str) -> Result<Option<&'static LocalGovernment>, String> {
+    if !is_valid_code(code) {
+        return Err(format!("地方公共団体コード{}の形式または検査数字が正しくありません", code));
+    }
+    Ok(lookup(code))
+}
+
+/// 住所から、その住所が属する市区町村を地方公共団体コード表に基づいて判定する関数
+///
+/// 郡名（例: 「入間郡毛呂山町」）は読み飛ばし、政令指定都市の区（例: 「名古屋市中区」）は
+/// 表に区の行があれば区、無ければ市として判定する。
+/// 住所に都道府県名が無い場合は、`jisx0402_hint`（取得元の地方公共団体コードなど）の都道府県内で判定する。
+pub fn attribute_municipality(address: &str, jisx0402_hint: Option<&str>) -> Option<&'static LocalGovernment> {
+    let parsed = parse_address(&normalize_address(address), jisx0402_hint);
+    let municipality = parsed.municipality.as_deref()?;
+    let prefecture = parsed.prefecture.as_deref();
+    let find = |name: &str| {
+        MASTER.iter().find(|government| {
+            !government.is_prefecture()
+                && government.name == name
+                && prefecture.is_none_or(|prefecture| government.prefecture == prefecture)
+        })
+    };
+    parsed
+        .ward
+        .as_deref()
+        .and_then(|ward| find(&format!("{}{}", municipality, ward)))
+        .or_else(|| find(municipality))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loads_every_row_with_a_valid_check_digit() {
+        assert_eq!(all().len(), MASTER_CSV.lines().skip(1).count());
+        assert_eq!(all().iter().filter(|government| government.is_prefecture()).count(), 47);
+        assert_eq!(lookup("123226").map(|government| government.name), Some("酒々井町"));
+        assert_eq!(lookup("272221").map(|government| government.name), Some("羽曳野市"));
+    }
+
+    #[test]
+    fn matches_designated_city_rather_than_ward() {
+        let government = match_municipality(Some("北海道"), "札幌市中央区北1条西2丁目").unwrap();
+        assert_eq!(government.code, "011002");
+        let government = attribute_municipality("北海道札幌市中央区北1条西2丁目", None).unwrap();
+        assert_eq!(government.code, "011011");
+    }
+}
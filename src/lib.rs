@@ -1,22 +1,50 @@
-use std::fs;
-use std::io::Write;
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Mutex;
-use serde_json::Value;
-use chrono::{Local, NaiveTime, DateTime, Utc, Datelike, Timelike};
+use serde_json::{json, Value};
+use chrono::{DateTime, FixedOffset};
 use regex::Regex;
 use reqwest::blocking::Client;
 use reqwest::header::HeaderMap;
 use encoding_rs::SHIFT_JIS;
 
+use crate::address::{normalize_address, parse_address, validate_address};
+use crate::category::classify;
+use crate::geocode::{geocode, municipality_centroid};
+use crate::location::parse_location;
+use crate::status::IncidentStatus;
+use crate::units::extract_units;
+
+pub use crate::context::RunContext;
+
+pub mod address;
+pub mod archive;
+pub mod category;
+pub mod context;
+#[cfg(feature = "sqlite")]
+pub mod database;
+pub mod department;
+pub mod envelope;
+pub mod feed;
+pub mod geocode;
+pub mod health;
+pub mod jisx0402;
+pub mod location;
+pub mod output;
 pub mod parse;
+pub mod schema;
+pub mod state;
+pub mod stats;
+pub mod status;
+pub mod units;
 
 lazy_static::lazy_static! {
     /// プロセス内HTTPレスポンスキャッシュ
     ///
     /// 同一プロセス内で同じURLへの複数回のリクエストを1回に削減します。
     /// プロセス終了時に自動的にクリアされます。
-    static ref SOURCE_CACHE: Mutex<HashMap<String, String>> = Mutex::new(HashMap::new());
+    /// キャッシュから取得した場合も取得状況を記録できるよう、HTTPステータスコードとともに保持します。
+    static ref SOURCE_CACHE: Mutex<HashMap<String, (u16, String)>> = Mutex::new(HashMap::new());
 }
 
 pub const ACCESS_UA: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:141.0) Gecko/20100101 Firefox/141.0 edbot v0.1.1(https://github.com/oageo/emergency-dispatch)";
@@ -81,8 +109,9 @@ pub fn get_source_with_config(config: &HttpRequestConfig) -> Result<String, Box<
     // キャッシュチェック
     {
         let cache = SOURCE_CACHE.lock().unwrap();
-        if let Some(cached) = cache.get(&config.url) {
+        if let Some((status, cached)) = cache.get(&config.url) {
             println!("  [キャッシュ] {}", config.url);
+            health::record_http_status(*status);
             return Ok(cached.clone());
         }
     }
@@ -122,6 +151,17 @@ pub fn get_source_with_config(config: &HttpRequestConfig) -> Result<String, Box<
                 return Err(Box::new(e));
             }
         };
+    let status = res.status().as_u16();
+    health::record_http_status(status);
+
+    // 4xx・5xxの応答はエラーページを解析しないよう取得失敗とする
+    let res = match res.error_for_status() {
+        Ok(response) => response,
+        Err(e) => {
+            eprintln!("  [取得失敗] {}: {}", config.url, e);
+            return Err(Box::new(e));
+        }
+    };
 
     let body = if config.use_shift_jis {
         let body_bytes = res.bytes()?;
@@ -134,7 +174,7 @@ pub fn get_source_with_config(config: &HttpRequestConfig) -> Result<String, Box<
     // キャッシュに保存
     {
         let mut cache = SOURCE_CACHE.lock().unwrap();
-        cache.insert(config.url.clone(), body.clone());
+        cache.insert(config.url.clone(), (status, body.clone()));
     }
 
     Ok(body)
@@ -158,6 +198,186 @@ pub fn to_half_width(s: &str) -> String {
         .collect()
 }
 
+/// 出動情報1件を正規化し、出動種別の分類や出動車両、住所の構成要素、道路などの場所の情報といった派生フィールドを付与する関数
+fn enrich_disaster(jisx0402: &str, disaster: &mut Value) {
+    // 状況を判定していないパーサーの出動情報は出動中として扱う
+    if disaster["status"].as_str().is_none() {
+        disaster["status"] = json!(IncidentStatus::Dispatched.as_str());
+    }
+
+    if let Some(disaster_type) = disaster["type"].as_str() {
+        let (category, subcategory) = classify(disaster_type);
+        disaster["category"] = json!(category.as_str());
+        disaster["subcategory"] = json!(subcategory.map(|s| s.as_str()));
+    }
+
+    // 出動種別に続く文に車両・部隊が書かれている場合は取り出す
+    if let Some(detail) = disaster["detail"].as_str() {
+        let units = extract_units(detail);
+        if !units.is_empty() {
+            disaster["units"] = Value::Array(units.iter().map(|unit| unit.to_json()).collect());
+        }
+    }
+
+    let Some(address) = disaster["address"].as_str().map(normalize_address) else {
+        return;
+    };
+
+    let parsed = parse_address(&address, Some(jisx0402));
+    if let Some(warning) = validate_address(&parsed, jisx0402) {
+        eprintln!("  [住所検証] {}: {}", address, warning);
+    }
+
+    disaster["address"] = Value::String(address);
+    disaster["prefecture"] = json!(parsed.prefecture);
+    disaster["municipality"] = json!(parsed.municipality);
+    disaster["ward"] = json!(parsed.ward);
+    disaster["town"] = json!(parsed.town);
+    disaster["chome"] = json!(parsed.chome);
+    disaster["remainder"] = json!(parsed.remainder);
+    disaster["location"] = parse_location(&parsed.local_part()).to_json();
+
+    // 地名辞書が無い場合や市区町村も特定できない場合は座標をnullとする
+    let geocoded = geocode(&parsed, jisx0402);
+    disaster["lat"] = json!(geocoded.map(|g| (g.lat * 1e6).round() / 1e6));
+    disaster["lon"] = json!(geocoded.map(|g| (g.lon * 1e6).round() / 1e6));
+    disaster["precision"] = json!(geocoded.map(|g| g.precision.as_str()));
+}
+
+/// パーサーが生成した出動情報を正規化し、出力先の`（地方公共団体コード）.json`へ書き出す関数
+///
+/// 住所はソースごとの表記揺れを吸収するため、`normalize_address`で正規化してから保存する。
+/// あわせて出動種別の分類と、住所を都道府県・市区町村・区・町名・丁目・残りに分解したフィールド、
+/// 地名辞書から求めた座標、状態ファイルに基づく出動情報の識別子（`id`）を付与する。
+/// 新たに観測した出動情報と状況が変わった出動情報は、履歴のディレクトリが指定されていれば`YYYY-MM-DD.jsonl`へ追記する。
+/// 取得した日時（`fetched_at`）と、取得できたことを表す`status`（`ok`）もあわせて書き出す。
+pub fn save_disaster_json(ctx: &RunContext, output: &Value) -> Result<(), Box<dyn std::error::Error>> {
+    let mut output = output.clone();
+    let jisx0402 = output["jisx0402"]
+        .as_str()
+        .ok_or("出動情報に地方公共団体コードが含まれていません")?
+        .to_string();
+
+    // 検査数字の誤りは書き出さずにエラーとし、表に無いだけの場合は警告に留める
+    match jisx0402::verify_code(&jisx0402)? {
+        Some(government) => {
+            output["prefecture"] = json!(government.prefecture);
+            output["name"] = json!(government.display_name());
+            output["name_kana"] = json!(government.display_kana());
+        }
+        None => eprintln!("  [コード検証] 地方公共団体コード{}が地方公共団体コード表にありません", jisx0402),
+    }
+    if let Some(department) = department::for_municipality(&jisx0402) {
+        output["department"] = json!(department.id);
+    }
+
+    output["fetched_at"] = json!(ctx.state().observed_at().to_rfc3339_opts(chrono::SecondsFormat::Secs, false));
+    output["status"] = json!(health::SourceStatus::Ok.as_str());
+    let mut parser_outputs = vec![];
+    if let Some(disasters) = output["disasters"].as_array_mut() {
+        for (index, disaster) in disasters.iter_mut().enumerate() {
+            parser_outputs.push(disaster.clone());
+            enrich_disaster(&jisx0402, disaster);
+            // 識別子は検証に成功してから割り当てるため、検証の間は仮の識別子とする
+            disaster["id"] = json!(format!("{}-{}", jisx0402, index));
+        }
+    }
+
+    // JSON Schemaに合わない場合は、状態・履歴に反映せずにこのソースのみを失敗とする（`mark_failed_sources`で`unavailable`となる）
+    let parser = ctx.health().current_parser().map(str::to_string);
+    if let Err(e) = schema::validate_municipality(&jisx0402, &envelope::wrap_municipality(output.clone(), parser.as_deref())) {
+        if let Some(e) = e.downcast_ref::<schema::SchemaError>() {
+            for problem in &e.problems {
+                eprintln!("  [出力検証] {}.json: {}", jisx0402, problem);
+            }
+        }
+        return Err(e);
+    }
+
+    // 出動情報が無い場合も、取得できたソースとして記録する
+    ctx.state().mark_source(&jisx0402);
+    if let Some(disasters) = output["disasters"].as_array_mut() {
+        let observed_at = ctx.state().observed_at();
+        for (disaster, parser_output) in disasters.iter_mut().zip(&parser_outputs) {
+            // 実行をまたいで変わらない識別子を状態ファイルから割り当てる
+            let (id, observation) = ctx.state().observe(&jisx0402, disaster);
+            disaster["id"] = json!(id);
+
+            // 新たな出動情報と状況の変化は履歴へ追記する（追記に失敗しても出動情報の書き出しは続ける）
+            if let Some(dir) = ctx.archive_dir()
+                && let Err(e) = archive::record(dir, &jisx0402, &id, &observation, parser_output, disaster, observed_at)
+            {
+                eprintln!("  [履歴] {}: 履歴の追記に失敗しました: {}", id, e);
+            }
+        }
+    }
+
+    let output = envelope::wrap_municipality(output, parser.as_deref());
+    ctx.write(&format!("{}.json", jisx0402), output.to_string().as_bytes())?;
+    Ok(())
+}
+
+/// 一部事務組合などの消防本部が一括で掲載している出動情報を、構成市町村ごとに振り分けて書き出す関数
+///
+/// 各出動情報の住所から`Department::member_for_address`で市区町村を判定し、構成市町村ごとに`save_disaster_json`で書き出す。
+/// 出動が無い構成市町村についても、空の出動情報を書き出す。
+/// 書き出しに失敗した構成市町村（JSON Schemaに合わない場合など）は、他の構成市町村の書き出しを止めずに、その地方公共団体コードについてのみ失敗として記録する。
+/// 市区町村を判定できなかった出動情報や、構成市町村以外と判定された出動情報は、`unattributed.json`へ回す。
+pub fn save_department_json(ctx: &RunContext, department_id: &str, source: Value, disasters: Vec<Value>) -> Result<(), Box<dyn std::error::Error>> {
+    let department = department::lookup(department_id)
+        .ok_or_else(|| format!("消防本部{}が登録されていません", department_id))?;
+    let hint = department.members.first().copied();
+
+    let mut routed: HashMap<&str, Vec<Value>> = department.members.iter().map(|code| (*code, vec![])).collect();
+    for mut disaster in disasters {
+        let address = disaster["address"].as_str().unwrap_or_default().to_string();
+        match department.member_for_address(&address).and_then(|code| routed.get_mut(code)) {
+            Some(member_disasters) => member_disasters.push(disaster),
+            None => {
+                eprintln!(
+                    "  [振り分け] {}（{}）: 構成市町村に振り分けられない住所です: {}",
+                    department.name,
+                    department.kind.label(),
+                    address
+                );
+                let attributed = jisx0402::attribute_municipality(&address, hint);
+                let jisx0402 = attributed.map_or(hint.unwrap_or_default(), |government| government.code);
+                enrich_disaster(jisx0402, &mut disaster);
+                disaster["jisx0402"] = json!(attributed.map(|government| government.code));
+                disaster["department"] = json!(department.id);
+                disaster["source"] = source.clone();
+                ctx.unattributed().push(disaster);
+            }
+        }
+    }
+
+    // 構成市町村ごとに検証して書き出し、失敗した構成市町村のみを取得の失敗として記録する
+    for code in department.members {
+        let result = save_disaster_json(ctx, &json!({
+            "jisx0402": code,
+            "source": source,
+            "disasters": routed.remove(code).unwrap_or_default()
+        }));
+        if let Err(e) = result {
+            eprintln!("  [取得失敗] {}: {}", code, e);
+            ctx.health().fail_source(code, e.as_ref());
+        }
+    }
+    Ok(())
+}
+
+/// 構成市町村に振り分けられなかった出動情報を`unattributed.json`に保存する関数
+///
+/// 各出動情報には、取得元の消防本部の識別子（`department`）とソース（`source`）、
+/// 判定できた場合は構成市町村以外の地方公共団体コード（`jisx0402`）を付与する。
+pub fn generate_unattributed_json(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    let disasters = std::mem::take(&mut *ctx.unattributed());
+    let count = disasters.len();
+    ctx.write("unattributed.json", json!({ "disasters": disasters }).to_string().as_bytes())?;
+    println!("振り分けられなかった出動情報が生成されました: {}（{}件）", ctx.location("unattributed.json"), count);
+    Ok(())
+}
+
 use crate::parse::parse_011002::return_011002;
 use crate::parse::parse_012025::return_012025;
 use crate::parse::parse_012131::return_012131;
@@ -166,518 +386,655 @@ use crate::parse::parse_012246::return_012246;
 use crate::parse::parse_012319::return_012319;
 use crate::parse::parse_012343::return_012343;
 use crate::parse::parse_022098::return_022098;
-use crate::parse::parse_062049::return_062049;
+use crate::parse::parse_sakata_chiku::return_sakata_chiku;
 use crate::parse::parse_062103::return_062103;
-use crate::parse::parse_064289::return_064289;
-use crate::parse::parse_064611::return_064611;
-use crate::parse::parse_072036::return_072036;
-use crate::parse::parse_072117::return_072117;
-use crate::parse::parse_075213::return_075213;
-use crate::parse::parse_075221::return_075221;
+use crate::parse::parse_koriyama_chiho::return_koriyama_chiho;
 use crate::parse::parse_082031::return_082031;
 use crate::parse::parse_083020::return_083020;
 use crate::parse::parse_092011::return_092011;
 use crate::parse::parse_112038::return_112038;
-use crate::parse::parse_112089::return_112089;
-use crate::parse::parse_112097::return_112097;
-use crate::parse::parse_112127::return_112127;
-use crate::parse::parse_112151::return_112151;
-use crate::parse::parse_112259::return_112259;
-use crate::parse::parse_112399::return_112399;
-use crate::parse::parse_112411::return_112411;
-use crate::parse::parse_112429::return_112429;
-use crate::parse::parse_113263::return_113263;
-use crate::parse::parse_113271::return_113271;
-use crate::parse::parse_113417::return_113417;
-use crate::parse::parse_113425::return_113425;
-use crate::parse::parse_113433::return_113433;
-use crate::parse::parse_113476::return_113476;
-use crate::parse::parse_113484::return_113484;
-use crate::parse::parse_113492::return_113492;
-use crate::parse::parse_113697::return_113697;
+use crate::parse::parse_saitama_seibu::return_saitama_seibu;
+use crate::parse::parse_hiki::return_hiki;
+use crate::parse::parse_sakado_tsurugashima::return_sakado_tsurugashima;
+use crate::parse::parse_nishiiruma::return_nishiiruma;
 use crate::parse::parse_121002::return_121002;
 use crate::parse::parse_122025::return_122025;
 use crate::parse::parse_122033::return_122033;
-use crate::parse::parse_122050::return_122050;
+use crate::parse::parse_awa::return_awa;
 use crate::parse::parse_122068::return_122068;
-use crate::parse::parse_122106::return_122106;
-use crate::parse::parse_122114::return_122114;
-use crate::parse::parse_122122::return_122122;
-use crate::parse::parse_122131::return_122131;
+use crate::parse::parse_chosei::return_chosei;
+use crate::parse::parse_narita::return_narita;
+use crate::parse::parse_sakura_yachimata_shisui::return_sakura_yachimata_shisui;
+use crate::parse::parse_sanbu::return_sanbu;
 use crate::parse::parse_122157::return_122157;
 use crate::parse::parse_122173::return_122173;
-use crate::parse::parse_122181::return_122181;
+use crate::parse::parse_isumi::return_isumi;
 use crate::parse::parse_122190::return_122190;
-use crate::parse::parse_122238::return_122238;
 use crate::parse::parse_122254::return_122254;
 use crate::parse::parse_122262::return_122262;
 use crate::parse::parse_122289::return_122289;
 use crate::parse::parse_122297::return_122297;
-use crate::parse::parse_122301::return_122301;
-use crate::parse::parse_122319::return_122319;
-use crate::parse::parse_122327::return_122327;
+use crate::parse::parse_inzai::return_inzai;
 use crate::parse::parse_122335::return_122335;
-use crate::parse::parse_122343::return_122343;
-use crate::parse::parse_122351::return_122351;
-use crate::parse::parse_122360::return_122360;
-use crate::parse::parse_122378::return_122378;
-use crate::parse::parse_122386::return_122386;
-use crate::parse::parse_122394::return_122394;
-use crate::parse::parse_123013::return_123013;
+use crate::parse::parse_sosa_yokoshibahikari::return_sosa_yokoshibahikari;
+use crate::parse::parse_katori::return_katori;
 use crate::parse::parse_123293::return_123293;
-use crate::parse::parse_123421::return_123421;
-use crate::parse::parse_123471::return_123471;
-use crate::parse::parse_123498::return_123498;
-use crate::parse::parse_124036::return_124036;
-use crate::parse::parse_124095::return_124095;
-use crate::parse::parse_124109::return_124109;
-use crate::parse::parse_124214::return_124214;
-use crate::parse::parse_124222::return_124222;
-use crate::parse::parse_124231::return_124231;
-use crate::parse::parse_124249::return_124249;
-use crate::parse::parse_124265::return_124265;
-use crate::parse::parse_124273::return_124273;
-use crate::parse::parse_124419::return_124419;
-use crate::parse::parse_124435::return_124435;
-use crate::parse::parse_124630::return_124630;
 use crate::parse::parse_141003::return_141003;
 use crate::parse::parse_141305::return_141305;
-use crate::parse::parse_142018::return_142018;
-use crate::parse::parse_142107::return_142107;
+use crate::parse::parse_yokosuka::return_yokosuka;
 use crate::parse::parse_151009::return_151009;
 use crate::parse::parse_152021::return_152021;
-use crate::parse::parse_152137::return_152137;
-use crate::parse::parse_153427::return_153427;
+use crate::parse::parse_tsubame_yahiko::return_tsubame_yahiko;
 use crate::parse::parse_172031::return_172031;
 use crate::parse::parse_231002::return_231002;
 use crate::parse::parse_232068::return_232068;
 use crate::parse::parse_261009::return_261009;
-use crate::parse::parse_272141::return_272141;
-use crate::parse::parse_272167::return_272167;
-use crate::parse::parse_272213::return_272213;
-use crate::parse::parse_272230::return_272230;
-use crate::parse::parse_272264::return_272264;
+use crate::parse::parse_osaka_minami::return_osaka_minami;
 use crate::parse::parse_272272::return_272272;
-use crate::parse::parse_273813::return_273813;
-use crate::parse::parse_273821::return_273821;
-use crate::parse::parse_273830::return_273830;
 use crate::parse::parse_282189::return_282189;
 use crate::parse::parse_292010::return_292010;
 use crate::parse::parse_292095::return_292095;
 use crate::parse::parse_322016::return_322016;
-use crate::parse::parse_342033::return_342033;
-use crate::parse::parse_342122::return_342122;
-use crate::parse::parse_344311::return_344311;
-use crate::parse::parse_352047::return_352047;
-use crate::parse::parse_355020::return_355020;
+use crate::parse::parse_higashihiroshima::return_higashihiroshima;
+use crate::parse::parse_hagi::return_hagi;
 use crate::parse::parse_401005::return_401005;
 use crate::parse::parse_401307::return_401307;
-use crate::parse::parse_402231::return_402231;
-use crate::parse::parse_403458::return_403458;
-use crate::parse::parse_412015::return_412015;
-use crate::parse::parse_412040::return_412040;
-use crate::parse::parse_412082::return_412082;
-use crate::parse::parse_412104::return_412104;
-use crate::parse::parse_413275::return_413275;
-
-pub fn get_all() -> Result<(), Box<dyn std::error::Error>> {
+use crate::parse::parse_kasuya_hokubu::return_kasuya_hokubu;
+use crate::parse::parse_saga_koiki::return_saga_koiki;
+
+/// 全てのパーサーを実行し、失敗したパーサーの数を返す関数
+pub fn get_all(ctx: &RunContext) -> Result<usize, Box<dyn std::error::Error>> {
     let mut error_count = 0;
-    
-    // マクロで各返却関数を呼び出し、エラーをハンドル
+    let mut parser_count = 0;
+
+    // 同じ`RunContext`で再び実行した場合も、前回の実行結果を引き継がない
+    *ctx.health() = health::RunHealth::default();
+    ctx.unattributed().clear();
+
+    // マクロで各返却関数を呼び出し、実行結果を記録してエラーをハンドル
     macro_rules! call_parser {
-        ($func:expr) => {
-            if let Err(e) = $func {
+        ($func:ident) => {
+            parser_count += 1;
+            let start = ctx.health().begin(stringify!($func));
+            let result = $func(ctx);
+            ctx.health().finish(stringify!($func), start, &result);
+            if let Err(e) = result {
                 eprintln!("取得失敗: {}", e);
                 error_count += 1;
             }
         };
     }
 
-    call_parser!(return_011002());
-    call_parser!(return_012025());
-    call_parser!(return_012131());
-    call_parser!(return_012173());
-    call_parser!(return_012246());
-    call_parser!(return_012319());
-    call_parser!(return_012343());
-    call_parser!(return_022098());
-    call_parser!(return_062049());
-    call_parser!(return_062103());
-    call_parser!(return_064289());
-    call_parser!(return_064611());
-    call_parser!(return_072036());
-    call_parser!(return_072117());
-    call_parser!(return_075213());
-    call_parser!(return_075221());
-    call_parser!(return_082031());
-    call_parser!(return_083020());
-    call_parser!(return_092011());
-    call_parser!(return_112038());
-    call_parser!(return_112089());
-    call_parser!(return_112097());
-    call_parser!(return_112127());
-    call_parser!(return_112151());
-    call_parser!(return_112259());
-    call_parser!(return_112399());
-    call_parser!(return_112411());
-    call_parser!(return_112429());
-    call_parser!(return_113263());
-    call_parser!(return_113271());
-    call_parser!(return_113417());
-    call_parser!(return_113425());
-    call_parser!(return_113433());
-    call_parser!(return_113476());
-    call_parser!(return_113484());
-    call_parser!(return_113492());
-    call_parser!(return_113697());
-    call_parser!(return_121002());
-    call_parser!(return_122025());
-    call_parser!(return_122033());
-    call_parser!(return_122050());
-    call_parser!(return_122068());
-    call_parser!(return_122106());
-    call_parser!(return_122114());
-    call_parser!(return_122122());
-    call_parser!(return_122131());
-    call_parser!(return_122157());
-    call_parser!(return_122173());
-    call_parser!(return_122181());
-    call_parser!(return_122190());
-    call_parser!(return_122238());
-    call_parser!(return_122254());
-    call_parser!(return_122262());
-    call_parser!(return_122289());
-    call_parser!(return_122297());
-    call_parser!(return_122301());
-    call_parser!(return_122319());
-    call_parser!(return_122327());
-    call_parser!(return_122335());
-    call_parser!(return_122343());
-    call_parser!(return_122351());
-    call_parser!(return_122360());
-    call_parser!(return_122378());
-    call_parser!(return_122386());
-    call_parser!(return_122394());
-    call_parser!(return_123013());
-    call_parser!(return_123293());
-    call_parser!(return_123421());
-    call_parser!(return_123471());
-    call_parser!(return_123498());
-    call_parser!(return_124036());
-    call_parser!(return_124095());
-    call_parser!(return_124109());
-    call_parser!(return_124214());
-    call_parser!(return_124222());
-    call_parser!(return_124231());
-    call_parser!(return_124249());
-    call_parser!(return_124265());
-    call_parser!(return_124273());
-    call_parser!(return_124419());
-    call_parser!(return_124435());
-    call_parser!(return_124630());
-    call_parser!(return_141003());
-    call_parser!(return_141305());
-    call_parser!(return_142018());
-    call_parser!(return_142107());
-    call_parser!(return_151009());
-    call_parser!(return_152021());
-    call_parser!(return_152137());
-    call_parser!(return_153427());
-    call_parser!(return_172031());
-    call_parser!(return_231002());
-    call_parser!(return_232068());
-    call_parser!(return_261009());
-    call_parser!(return_272141());
-    call_parser!(return_272167());
-    call_parser!(return_272213());
-    call_parser!(return_272230());
-    call_parser!(return_272264());
-    call_parser!(return_272272());
-    call_parser!(return_273813());
-    call_parser!(return_273821());
-    call_parser!(return_273830());
-    call_parser!(return_282189());
-    call_parser!(return_292010());
-    call_parser!(return_292095());
-    call_parser!(return_322016());
-    call_parser!(return_342033());
-    call_parser!(return_342122());
-    call_parser!(return_344311());
-    call_parser!(return_352047());
-    call_parser!(return_355020());
-    call_parser!(return_401005());
-    call_parser!(return_401307());
-    call_parser!(return_402231());
-    call_parser!(return_403458());
-    call_parser!(return_412015());
-    call_parser!(return_412040());
-    call_parser!(return_412082());
-    call_parser!(return_412104());
-    call_parser!(return_413275());
+    call_parser!(return_011002);
+    call_parser!(return_012025);
+    call_parser!(return_012131);
+    call_parser!(return_012173);
+    call_parser!(return_012246);
+    call_parser!(return_012319);
+    call_parser!(return_012343);
+    call_parser!(return_022098);
+    call_parser!(return_sakata_chiku);
+    call_parser!(return_062103);
+    call_parser!(return_koriyama_chiho);
+    call_parser!(return_082031);
+    call_parser!(return_083020);
+    call_parser!(return_092011);
+    call_parser!(return_112038);
+    call_parser!(return_saitama_seibu);
+    call_parser!(return_hiki);
+    call_parser!(return_sakado_tsurugashima);
+    call_parser!(return_nishiiruma);
+    call_parser!(return_121002);
+    call_parser!(return_122025);
+    call_parser!(return_122033);
+    call_parser!(return_awa);
+    call_parser!(return_122068);
+    call_parser!(return_chosei);
+    call_parser!(return_narita);
+    call_parser!(return_sakura_yachimata_shisui);
+    call_parser!(return_sanbu);
+    call_parser!(return_122157);
+    call_parser!(return_122173);
+    call_parser!(return_isumi);
+    call_parser!(return_122190);
+    call_parser!(return_122254);
+    call_parser!(return_122262);
+    call_parser!(return_122289);
+    call_parser!(return_122297);
+    call_parser!(return_inzai);
+    call_parser!(return_122335);
+    call_parser!(return_sosa_yokoshibahikari);
+    call_parser!(return_katori);
+    call_parser!(return_123293);
+    call_parser!(return_141003);
+    call_parser!(return_141305);
+    call_parser!(return_yokosuka);
+    call_parser!(return_151009);
+    call_parser!(return_152021);
+    call_parser!(return_tsubame_yahiko);
+    call_parser!(return_172031);
+    call_parser!(return_231002);
+    call_parser!(return_232068);
+    call_parser!(return_261009);
+    call_parser!(return_osaka_minami);
+    call_parser!(return_272272);
+    call_parser!(return_282189);
+    call_parser!(return_292010);
+    call_parser!(return_292095);
+    call_parser!(return_322016);
+    call_parser!(return_higashihiroshima);
+    call_parser!(return_hagi);
+    call_parser!(return_401005);
+    call_parser!(return_401307);
+    call_parser!(return_kasuya_hokubu);
+    call_parser!(return_saga_koiki);
 
     // すべてのパーサーが失敗した場合はエラーを返す
     if error_count > 0 {
         eprintln!("\n合計 {} 件のパーサーが失敗しました", error_count);
-        if error_count == 87 {
-            // 87はすべてのパーサー数（error_count がこの値の場合、全て失敗）
+        if error_count == parser_count {
             return Err("すべてのパーサーが失敗しました".into());
         }
     }
     
-    Ok(())
+    Ok(error_count)
 }
 
-// distディレクトリ内の「6桁の数字.json」ファイル名を取得し、Vecへ格納する関数
-pub fn get_all_json() -> Result<Vec<String>, Box<dyn std::error::Error>> {
-    let re = Regex::new(r"^\d{6}\.json$")?;
-    let files = fs::read_dir("dist")?
-        .filter_map(|entry| {
-            let entry = entry.ok()?;
-            let file_name = entry.file_name().into_string().ok()?;
-            if re.is_match(&file_name) {
-                Some(format!("dist/{}", file_name))
-            } else {
-                None
-            }
-        })
-        .collect::<Vec<_>>();
+// 出力先の「6桁の数字.json」ファイル名を取得し、Vecへ格納する関数
+//
+// 対応していない地方公共団体コードのファイル（コードの訂正前のファイルなど）は、出力先に残っていても含めない。
+pub fn get_all_json(ctx: &RunContext) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let re = Regex::new(r"^(\d{6})\.json$")?;
+    let mut files = vec![];
+    for file_name in ctx.list()? {
+        let Some(code) = re.captures(&file_name).and_then(|captures| captures.get(1)) else {
+            continue;
+        };
+        if department::is_supported(code.as_str()) {
+            files.push(file_name);
+        } else {
+            eprintln!("  [対応外] {}: 対応している地方公共団体コードではないため、集計に含めません", ctx.location(&file_name));
+        }
+    }
     Ok(files)
 }
 
-// 対応している地方公共団体コードの一覧を`list.json`に保存する関数
-pub fn generate_list_json() -> Result<(), Box<dyn std::error::Error>> {
-    let files = get_all_json()?;
+// 対応している地方公共団体コードの一覧を、地方公共団体コード表の名称とともに`list.json`に保存する関数
+pub fn generate_list_json(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    let files = get_all_json(ctx)?;
     let mut list = vec![];
     for file in files {
-        let data = fs::read_to_string(&file)?;
-        let json: Value = serde_json::from_str(&data)?;
-        if let Some(jisx0402) = json["jisx0402"].as_str() {
-            list.push(jisx0402.to_string());
+        let Some(json) = envelope::read(ctx, &file)? else {
+            continue;
+        };
+        if let Some(code) = json["jisx0402"].as_str() {
+            match jisx0402::verify_code(code) {
+                Ok(government) => list.push(json!({
+                    "jisx0402": code,
+                    "department": department::for_municipality(code).map(|d| d.id),
+                    "prefecture": government.map(|g| g.prefecture),
+                    "name": government.map(|g| g.display_name()),
+                    "name_kana": government.map(|g| g.display_kana())
+                })),
+                Err(e) => eprintln!("{}: {}", file, e),
+            }
         }
     }
-    list.sort(); 
-    let list_json_array = serde_json::to_string(&list)?;
-    let mut file = fs::File::create("dist/list.json")?;
-    file.write_all(list_json_array.as_bytes())?;
-    println!("対応している地方公共団体コードの一覧が生成されました: dist/list.json");
+    list.sort_by(|a, b| a["jisx0402"].as_str().cmp(&b["jisx0402"].as_str()));
+    // 第1版の形式では、従来どおり地方公共団体コードの文字列の配列とする
+    let list = if envelope::is_compat_mode() {
+        json!(list.iter().filter_map(|entry| entry["jisx0402"].as_str()).collect::<Vec<_>>())
+    } else {
+        json!(list)
+    };
+    let list_json_array = serde_json::to_string(&envelope::wrap(list, None, None))?;
+    ctx.write("list.json", list_json_array.as_bytes())?;
+    println!("対応している地方公共団体コードの一覧が生成されました: {}", ctx.location("list.json"));
     Ok(())
 }
 
-/// RSSフィードを生成する関数
+/// 全ての出動情報をまとめたRSSフィード(all_feed.xml)を生成する関数
+///
+/// Atom・JSON Feedも同時に生成する`generate_feeds`に置き換えられたため、互換のためにのみ残している。
+/// 従来どおり環境変数で指定された出力先（無ければ`dist`）へ生成する。
+#[deprecated(note = "generate_feedsを使用してください")]
 pub fn generate_rss_feed() -> Result<(), Box<dyn std::error::Error>> {
-    let mut all_disasters = vec![];
-    let files = get_all_json().expect("RSSフィードの生成中に、JSONファイルの取得に失敗しました");
+    generate_feeds(&RunContext::from_env()?)
+}
 
-    // 前回のguidマッピングを読み込み
-    let previous_guid_mapping = load_previous_guid_mapping();
+/// 全ての出動情報をまとめたフィードを、RSS 2.0(all_feed.xml)・Atom 1.0(all_feed.atom)・JSON Feed 1.1(all_feed.json)の各形式で生成する関数
+///
+/// 各形式は同じ項目の一覧から生成するため、同じ出動情報には形式によらず同じ識別子が付与される。
+pub fn generate_feeds(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    let files = get_all_json(ctx)?;
 
-    // 現在の日時を取得
-    let now = Local::now();
+    // 出動時刻は日本標準時のため、実行環境のタイムゾーンによらず日本標準時で日付を補う
+    let now = feed::now_jst();
+    // 更新・終息した項目は、前回の実行からのイベントに基づいて示す
+    let items = feed::collect_items(ctx, &files, ctx.state().events(), now)?;
+    let channel = feed::FeedChannel::nationwide(now);
 
-    // 各JSONファイルを読み込む
-    for file in files {
-        let data = fs::read_to_string(&file)?;
-        let json: Value = serde_json::from_str(&data)?;
-
-        if let (Some(source), Some(disasters), Some(jisx0402)) = (
-            json["source"].as_array(),
-            json["disasters"].as_array(),
-            json["jisx0402"].as_str()
-        ) {
-            if let (Some(source_name), Some(source_url)) = (
-                source.get(0).and_then(|s| s["name"].as_str()),
-                source.get(0).and_then(|s| s["url"].as_str()),
-            ) {
-                for disaster in disasters {
-                    if let (Some(time_str), Some(disaster_type), Some(address)) = (
-                        disaster["time"].as_str(),
-                        disaster["type"].as_str(),
-                        disaster["address"].as_str(),
-                    ) {
-                        // 時刻をNaiveTimeに変換
-                        if let Ok(parsed_time) = NaiveTime::parse_from_str(time_str, "%H:%M") {
-                            // 現在の日付を基準に日時を生成
-                            let mut disaster_date = now.date_naive();
-                            let disaster_datetime = disaster_date.and_time(parsed_time);
-
-                            // 実行時刻をNaiveDateTimeに変換
-                            let now_naive = now.naive_local();
-
-                            // 実行時刻と比較して10分以上未来の場合、1日前の日付を設定
-                            if disaster_datetime > now_naive + chrono::Duration::minutes(10) {
-                                disaster_date = disaster_date.pred_opt().unwrap_or(disaster_date); // 1日前の日付に変更
-                            }
-
-                            // 災害の本質（時間以外の情報）をキーとする
-                            let disaster_essence = format!("{}-{}", address, disaster_type);
-
-                            // 同一本質の災害には同じguidを使用、新規なら新しいguidを生成
-                            let guid = if let Some(existing_guid) = previous_guid_mapping.get(&disaster_essence) {
-                                existing_guid.clone() // 同一本質なら前回guidを再利用
-                            } else {
-                                // 新規災害なら新しいguidを生成（既存機構）
-                                format!("{}{:02}{:02}{:02}{:02}-{}",
-                                    disaster_date.year(),
-                                    disaster_date.month(),
-                                    disaster_date.day(),
-                                    parsed_time.hour(),
-                                    parsed_time.minute(),
-                                    jisx0402
-                                )
-                            };
-
-                            let iso8601_time = format!("{}T{}", disaster_date, parsed_time);
-                            all_disasters.push((
-                                iso8601_time,
-                                format!("{}（{}）", disaster_type, source_name), // タイトルに「disaster_type（source.name）」を表示
-                                address.to_string(),
-                                source_url.to_string(), // ソースURLを含める
-                                jisx0402.to_string(),
-                                guid,
-                            ));
-                        }
-                    }
-                }
-            }
-        }
-    }
+    ctx.write("all_feed.xml", &feed::write_rss(&channel, &items)?)?;
 
-    // 時間順にソート
-    all_disasters.sort_by_key(|(time, _, _, _, _, _)| {
-        DateTime::parse_from_rfc3339(time).unwrap_or_else(|_| Utc::now().into())
-    });
+    ctx.write("all_feed.atom", &feed::write_atom(&channel, &items)?)?;
 
-    // 重複するguidを解決（シーケンス番号を付与）
-    use std::collections::HashMap;
-    let mut guid_counts: HashMap<String, i32> = HashMap::new();
+    ctx.write("all_feed.json", serde_json::to_string_pretty(&feed::write_json_feed(&channel, &items))?.as_bytes())?;
 
-    for disaster in &mut all_disasters {
-        let base_guid = disaster.5.clone(); // guidは6番目の要素
-        let count = guid_counts.entry(base_guid.clone()).or_insert(0);
-        *count += 1;
+    println!(
+        "フィードが生成されました: {}, {}, {}",
+        ctx.location("all_feed.xml"),
+        ctx.location("all_feed.atom"),
+        ctx.location("all_feed.json")
+    );
+
+    // 市区町村ごと・都道府県ごとのフィードも、同じ項目の一覧から生成する
+    let mut codes = files
+        .iter()
+        .filter_map(|file| Path::new(file).file_stem()?.to_str().map(str::to_string))
+        .collect::<Vec<_>>();
+    codes.sort();
+    generate_area_feeds(ctx, &codes, &items, now)?;
+    Ok(())
+}
 
-        if *count > 1 {
-            disaster.5 = format!("{}-{:02}", base_guid, *count - 1);
+/// 市区町村ごと(feeds/（地方公共団体コード）.xml)・都道府県ごと(feeds/pref-（都道府県コード）.xml)のRSSフィードと、
+/// 生成したフィードの一覧(feeds/index.json)を生成する関数
+///
+/// 出動情報が無い市区町村・都道府県のフィードも、購読先が変わらないよう空のフィードとして生成する。
+fn generate_area_feeds(ctx: &RunContext, codes: &[String], items: &[feed::FeedItem], now: DateTime<FixedOffset>) -> Result<(), Box<dyn std::error::Error>> {
+    let mut index = vec![json!({
+        "id": "all",
+        "kind": "nationwide",
+        "jisx0402": null,
+        "prefecture": null,
+        "name": null,
+        "title": feed::FEED_TITLE,
+        "item_count": items.len(),
+        "files": {
+            "rss": "all_feed.xml",
+            "atom": "all_feed.atom",
+            "json": "all_feed.json"
         }
-    }
+    })];
+
+    let mut prefecture_codes = codes.iter().filter_map(|code| code.get(..2)).collect::<Vec<_>>();
+    prefecture_codes.dedup();
 
-    // RSSフィードを生成
-    let mut rss_feed = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
-    rss_feed.push_str(r#"<rss version="2.0"><channel>"#);
-    rss_feed.push_str("<title>日本の緊急車両出動フィード（非公式） by oageo</title>");
-    rss_feed.push_str("<link>https://github.com/oageo/emergency-dispatch</link>");
-    rss_feed.push_str(&format!("<description>全国の緊急車両出動情報を統一されたフォーマットで提供する。フィード生成日時: {}</description>", now.to_string()));
-    rss_feed.push_str(&format!("<lastBuildDate>{}</lastBuildDate>", now.to_rfc2822()));
-    rss_feed.push_str("<generator>emergency-dispatch</generator>");
-    rss_feed.push_str("<language>ja</language>");
+    for prefecture_code in prefecture_codes {
+        let channel = feed::FeedChannel::prefecture(prefecture_code, now);
+        let prefecture_items = items
+            .iter()
+            .filter(|item| item.jisx0402.starts_with(prefecture_code))
+            .cloned()
+            .collect::<Vec<_>>();
+        let path = format!("feeds/{}.xml", channel.id);
+        ctx.write(&path, &feed::write_rss(&channel, &prefecture_items)?)?;
 
-    for (time, title, address, source_url, _jisx0402, guid) in all_disasters {
-        rss_feed.push_str("<item>");
-        rss_feed.push_str(&format!("<title>{}</title>", title));
-        rss_feed.push_str(&format!("<description>{}</description>", address));
-        rss_feed.push_str(&format!("<link>{}</link>", source_url)); // ソースURLを含める
-        rss_feed.push_str(&format!("<pubDate>{}</pubDate>", time));
-        rss_feed.push_str(&format!("<guid isPermaLink=\"false\">{}</guid>", guid));
-        rss_feed.push_str("</item>");
+        let government = jisx0402::prefecture_of(prefecture_code);
+        index.push(json!({
+            "id": channel.id,
+            "kind": "prefecture",
+            "jisx0402": government.map(|g| g.code),
+            "prefecture": government.map(|g| g.prefecture),
+            "name": government.map(|g| g.prefecture),
+            "title": channel.title,
+            "item_count": prefecture_items.len(),
+            "files": { "rss": path }
+        }));
     }
 
-    rss_feed.push_str("</channel></rss>");
+    for code in codes {
+        let channel = feed::FeedChannel::municipality(code, now);
+        let municipality_items = items.iter().filter(|item| &item.jisx0402 == code).cloned().collect::<Vec<_>>();
+        let path = format!("feeds/{}.xml", channel.id);
+        ctx.write(&path, &feed::write_rss(&channel, &municipality_items)?)?;
 
-    // RSSフィードをファイルに保存
-    let mut file = fs::File::create("dist/all_feed.xml")?;
-    file.write_all(rss_feed.as_bytes())?;
+        let government = jisx0402::lookup(code);
+        index.push(json!({
+            "id": channel.id,
+            "kind": "municipality",
+            "jisx0402": code,
+            "prefecture": government.map(|g| g.prefecture),
+            "name": government.map(|g| g.display_name()),
+            "title": channel.title,
+            "item_count": municipality_items.len(),
+            "files": { "rss": path }
+        }));
+    }
 
-    println!("RSSフィードが生成されました: dist/all_feed.xml");
+    ctx.write("feeds/index.json", serde_json::to_string_pretty(&index)?.as_bytes())?;
+
+    println!("市区町村ごと・都道府県ごとのフィードが生成されました: {}", ctx.location("feeds/"));
     Ok(())
 }
 
+/// 市区町村ごとのファイルを1度だけ読み込み、データ本体を地方公共団体コードの順に返す関数
+///
+/// `generate_all_json`と`generate_stats_json`はこの結果を共有し、同じ時点の出動情報から生成する。
+pub fn collect_municipality_data(ctx: &RunContext) -> Result<Vec<Value>, Box<dyn std::error::Error>> {
+    let mut municipalities = vec![];
+    for file in get_all_json(ctx)? {
+        if let Some(json) = envelope::read(ctx, &file)? {
+            municipalities.push(json);
+        }
+    }
+    Ok(municipalities)
+}
 
 /// 災害情報があるJSONファイルをjisx0402をキーとした統合JSONファイル(all.json)として生成する関数
-pub fn generate_all_json() -> Result<(), Box<dyn std::error::Error>> {
-    let files = get_all_json()?;
+///
+/// `municipalities`は`collect_municipality_data`で読み込んだ市区町村ごとのファイルのデータ本体である。
+pub fn generate_all_json(ctx: &RunContext, municipalities: &[Value]) -> Result<(), Box<dyn std::error::Error>> {
     let mut all_data = serde_json::Map::new();
 
-    // 各JSONファイルを読み込む
-    for file in files {
-        let data = fs::read_to_string(&file)?;
-        let json: Value = serde_json::from_str(&data)?;
-
-        // 災害情報があるかチェック
-        if let Some(disasters) = json["disasters"].as_array() {
-            if !disasters.is_empty() {
-                // jisx0402をキーとして使用
-                if let Some(jisx0402) = json["jisx0402"].as_str() {
-                    // jisx0402フィールドを除いた残りのデータを格納
-                    let mut filtered_data = serde_json::Map::new();
-                    if let Some(source) = json.get("source") {
-                        filtered_data.insert("source".to_string(), source.clone());
-                    }
-                    filtered_data.insert("disasters".to_string(), Value::Array(disasters.clone()));
-                    
-                    all_data.insert(jisx0402.to_string(), Value::Object(filtered_data));
-                }
+    for json in municipalities {
+        // 災害情報があるもののみ、jisx0402をキーとして使用
+        let (Some(disasters), Some(jisx0402)) = (json["disasters"].as_array(), json["jisx0402"].as_str()) else {
+            continue;
+        };
+        if disasters.is_empty() {
+            continue;
+        }
+
+        // jisx0402フィールドを除いた残りのデータを格納
+        let mut filtered_data = serde_json::Map::new();
+        for key in ["prefecture", "name", "name_kana", "fetched_at", "status"] {
+            if let Some(value) = json.get(key) {
+                filtered_data.insert(key.to_string(), value.clone());
             }
         }
+        if let Some(source) = json.get("source") {
+            filtered_data.insert("source".to_string(), source.clone());
+        }
+        filtered_data.insert("disasters".to_string(), Value::Array(disasters.clone()));
+
+        all_data.insert(jisx0402.to_string(), Value::Object(filtered_data));
     }
 
     // all.jsonファイルに保存
-    let output = Value::Object(all_data);
-    let mut file = fs::File::create("dist/all.json")?;
-    file.write_all(serde_json::to_string_pretty(&output)?.as_bytes())?;
+    let output = envelope::wrap(Value::Object(all_data), None, None);
+    ctx.write("all.json", serde_json::to_string_pretty(&output)?.as_bytes())?;
 
-    println!("統合災害情報ファイルが生成されました: dist/all.json");
+    println!("統合災害情報ファイルが生成されました: {}", ctx.location("all.json"));
     Ok(())
 }
 
-/// 前回のRSSフィードから災害本質とguidのマッピングを読み込む関数
-fn load_previous_guid_mapping() -> HashMap<String, String> {
-    let mut guid_mapping = HashMap::new();
-
-    if let Ok(rss_content) = fs::read_to_string("dist/all_feed.xml") {
-        // 簡単なXML解析でdescription（住所）、title（災害種別）、guidを抽出
-        let lines: Vec<&str> = rss_content.lines().collect();
-        let mut i = 0;
-
-        while i < lines.len() {
-            if lines[i].trim().starts_with("<item>") {
-                let mut title = "";
-                let mut description = "";
-                let mut guid = "";
-                let mut j = i + 1;
-
-                while j < lines.len() && !lines[j].trim().starts_with("</item>") {
-                    let line = lines[j].trim();
-                    if line.starts_with("<title>") {
-                        title = line.trim_start_matches("<title>").trim_end_matches("</title>");
-                    } else if line.starts_with("<description>") {
-                        description = line.trim_start_matches("<description>").trim_end_matches("</description>");
-                    } else if line.starts_with("<guid ") {
-                        // <guid isPermaLink="false">GUID値</guid> から GUID値 を抽出
-                        if let Some(start) = line.find('>') {
-                            if let Some(end) = line.find("</guid>") {
-                                guid = &line[start + 1..end];
-                            }
-                        }
-                    }
-                    j += 1;
+/// 直近24時間・7日間・30日間の出動件数の集計(stats.json)を生成する関数
+///
+/// `all.json`と同じ`municipalities`（`collect_municipality_data`の結果）の出動情報と、履歴の`new`の行を合わせ、識別子で重複を除いて集計する。
+/// 出動時刻は、市区町村のファイルでは取得した日時、履歴では観測した日時を基準に日付を補う。
+pub fn generate_stats_json(ctx: &RunContext, municipalities: &[Value]) -> Result<(), Box<dyn std::error::Error>> {
+    let now = ctx.state().observed_at();
+    let mut incidents = vec![];
+
+    for json in municipalities {
+        let Some(jisx0402) = json["jisx0402"].as_str() else {
+            continue;
+        };
+        let fetched_at = json["fetched_at"]
+            .as_str()
+            .and_then(|time| DateTime::parse_from_rfc3339(time).ok())
+            .unwrap_or(now);
+        incidents.extend(
+            json["disasters"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|disaster| stats::StatsIncident::from_disaster(jisx0402, disaster, fetched_at)),
+        );
+    }
+
+    let from = (now - chrono::Duration::days(30)).date_naive();
+    let entries = ctx.archive_dir().map(|dir| archive::iter_range(dir, from, now.date_naive()));
+    for entry in entries.into_iter().flatten() {
+        match entry {
+            Ok(entry) => incidents.extend(stats::StatsIncident::from_archive_entry(&entry)),
+            Err(e) => eprintln!("  [履歴] 読み込めない行を読み飛ばします: {}", e),
+        }
+    }
+
+    let output = stats::generate(&incidents, now);
+    ctx.write("stats.json", serde_json::to_string_pretty(&output)?.as_bytes())?;
+    println!(
+        "出動件数の集計が生成されました: {}（直近24時間{}件）",
+        ctx.location("stats.json"),
+        output["windows"]["24h"]["total"]
+    );
+    Ok(())
+}
+
+/// 消防本部の一覧(departments.json)と、消防本部ごとの出動情報(departments/（識別子）.json)を生成する関数
+///
+/// 消防本部ごとの出動情報は、構成市町村の`（地方公共団体コード）.json`をまとめたものであり、
+/// 各出動情報にはどの市町村のものかを表す`jisx0402`を付与する。
+pub fn generate_department_json(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    let mut index = vec![];
+
+    for department in department::DEPARTMENTS {
+        let mut sources: Vec<Value> = vec![];
+        let mut disasters = vec![];
+        for code in department.members {
+            let Some(json) = envelope::read(ctx, &format!("{}.json", code))? else {
+                continue;
+            };
+            for source in json["source"].as_array().into_iter().flatten() {
+                if !sources.contains(source) {
+                    sources.push(source.clone());
                 }
+            }
+            for disaster in json["disasters"].as_array().into_iter().flatten() {
+                let mut disaster = disaster.clone();
+                disaster["jisx0402"] = json!(code);
+                disasters.push(disaster);
+            }
+        }
+
+        let mut report = department.to_json();
+        report["source"] = Value::Array(sources);
+        report["disasters"] = Value::Array(disasters);
+        ctx.write(&format!("departments/{}.json", department.id), report.to_string().as_bytes())?;
+
+        index.push(department.to_json());
+    }
+
+    ctx.write("departments.json", serde_json::to_string(&index)?.as_bytes())?;
+
+    println!(
+        "消防本部ごとの出動情報が生成されました: {}, {}",
+        ctx.location("departments.json"),
+        ctx.location("departments/")
+    );
+    Ok(())
+}
 
-                if !title.is_empty() && !description.is_empty() && !guid.is_empty() {
-                    // titleから災害種別を抽出（「災害種別（消防局名）」形式）
-                    if let Some((disaster_type, _)) = title.split_once("（") {
-                        // 災害の本質（時間以外の情報）
-                        let disaster_essence = format!("{}-{}", description, disaster_type);
-                        guid_mapping.insert(disaster_essence, guid.to_string());
-                    }
+/// 出動情報をGeoJSONのFeatureCollection(all.geojson)として生成する関数
+///
+/// 座標は各出動情報の`lat`・`lon`を使用し、求められていない場合は市区町村の代表点で代用する。
+/// いずれも求められない出動情報は`geometry`を`null`とする。
+pub fn generate_geojson(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    let files = get_all_json(ctx)?;
+    let mut features = vec![];
+
+    for file in files {
+        let Some(json) = envelope::read(ctx, &file)? else {
+            continue;
+        };
+
+        let (Some(disasters), Some(jisx0402)) = (json["disasters"].as_array(), json["jisx0402"].as_str()) else {
+            continue;
+        };
+        let source = json["source"].as_array().and_then(|source| source.first());
+
+        for disaster in disasters {
+            let coordinates = match (disaster["lat"].as_f64(), disaster["lon"].as_f64()) {
+                (Some(lat), Some(lon)) => Some((lat, lon, disaster["precision"].clone())),
+                _ => municipality_centroid(jisx0402).map(|(lat, lon)| (lat, lon, json!("municipality"))),
+            };
+            let (geometry, precision) = match coordinates {
+                // GeoJSONの座標は経度・緯度の順
+                Some((lat, lon, precision)) => (
+                    json!({
+                        "type": "Point",
+                        "coordinates": [(lon * 1e6).round() / 1e6, (lat * 1e6).round() / 1e6]
+                    }),
+                    precision,
+                ),
+                None => (Value::Null, Value::Null),
+            };
+
+            features.push(json!({
+                "type": "Feature",
+                "geometry": geometry,
+                "properties": {
+                    "type": disaster["type"],
+                    "category": disaster["category"],
+                    "subcategory": disaster["subcategory"],
+                    "status": disaster["status"],
+                    "time": disaster["time"],
+                    "address": disaster["address"],
+                    "precision": precision,
+                    "jisx0402": jisx0402,
+                    "source_name": source.map_or(Value::Null, |s| s["name"].clone()),
+                    "source_url": source.map_or(Value::Null, |s| s["url"].clone())
                 }
-                i = j;
-            } else {
-                i += 1;
+            }));
+        }
+    }
+
+    let output = json!({
+        "type": "FeatureCollection",
+        "features": features
+    });
+    ctx.write("all.geojson", serde_json::to_string_pretty(&output)?.as_bytes())?;
+
+    println!("GeoJSONファイルが生成されました: {}", ctx.location("all.geojson"));
+    Ok(())
+}
+
+/// 前回の実行からの出動情報の変化を、連番付きのイベントの一覧(events.json)として生成する関数
+///
+/// イベントは、新たな出動情報(`added`)、状況などの変化(`changed`)、鎮火などによる終了(`resolved`)、
+/// 前回は掲載されていて今回は掲載されていない出動情報(`disappeared`)のいずれかである。
+pub fn generate_events_json(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    let events = ctx.state().events().to_vec();
+    let output = json!({
+        "observed_at": ctx.state().observed_at().to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
+        "previous_observed_at": ctx.state().previous_observed_at().map(|time| time.to_rfc3339_opts(chrono::SecondsFormat::Secs, false)),
+        "first_sequence": events.first().map(|event| event["sequence"].clone()),
+        "last_sequence": events.last().map(|event| event["sequence"].clone()),
+        "events": events
+    });
+    ctx.write("events.json", serde_json::to_string_pretty(&output)?.as_bytes())?;
+
+    println!(
+        "前回の実行からの変化が生成されました: {}（{}件）",
+        ctx.location("events.json"),
+        output["events"].as_array().map_or(0, Vec::len)
+    );
+    Ok(())
+}
+
+/// 取得に失敗したソースの`（地方公共団体コード）.json`を、前回の出動情報がそのまま再利用されないよう更新する関数
+///
+/// 最後に取得した日時（`fetched_at`）から`health::stale_ttl()`以内であれば出動情報を残して`status`を`stale`とし、
+/// それを過ぎている場合や取得した日時が不明な場合は出動情報を空にして`status`を`unavailable`とする。
+/// JSON Schemaに合わなかったソース（`error_kind`が`schema`）も、前回の出動情報を残さずに`unavailable`とする。
+/// 一度も取得できていない市区町村も、`unavailable`のファイルを書き出す。
+pub fn mark_failed_sources(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    let now = ctx.state().observed_at();
+    let ttl = health::stale_ttl();
+    let health = ctx.health();
+    let attempts = health.attempts();
+
+    for code in health.failed_codes() {
+        let path = format!("{}.json", code);
+        let mut output = match envelope::read(ctx, &path)? {
+            Some(output) => output,
+            None => {
+                let government = jisx0402::lookup(&code);
+                json!({
+                    "jisx0402": code,
+                    "department": department::for_municipality(&code).map(|d| d.id),
+                    "prefecture": government.map(|g| g.prefecture),
+                    "name": government.map(|g| g.display_name()),
+                    "name_kana": government.map(|g| g.display_kana()),
+                    "source": [],
+                    "fetched_at": null
+                })
             }
+        };
+
+        let attempt = attempts.iter().find(|attempt| attempt.codes.contains(&code));
+        let fetched_at = output["fetched_at"].as_str().and_then(|time| DateTime::parse_from_rfc3339(time).ok());
+        // JSON Schemaに合わない出動情報は、前回取得したものも含めて出力しない
+        let status = if attempt.and_then(|attempt| attempt.error_for(&code)).is_some_and(|(kind, _)| kind == "schema") {
+            health::SourceStatus::Unavailable
+        } else {
+            health::SourceStatus::for_failed(fetched_at, now, ttl)
+        };
+        if status == health::SourceStatus::Unavailable {
+            output["disasters"] = json!([]);
         }
+        output["status"] = json!(status.as_str());
+        eprintln!("  [取得失敗] {}: {}として書き出します", code, status.as_str());
+
+        let output = envelope::wrap_municipality(output, attempt.map(|attempt| attempt.parser.as_str()));
+        ctx.write(&path, output.to_string().as_bytes())?;
     }
+    Ok(())
+}
 
-    guid_mapping
+/// ソースごとの取得状況(status.json)を生成する関数
+///
+/// `get_all`で記録したパーサーの実行結果を地方公共団体コードごとにまとめ、前回の`status.json`から
+/// 最後に成功した日時と連続して失敗した回数を引き継ぐ。
+pub fn generate_status_json(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    let previous = ctx.read_to_string("status.json")?
+        .and_then(|data| serde_json::from_str::<Value>(&data).ok())
+        .map_or(Value::Null, |json| json["sources"].clone());
+    let incident_count = |code: &str| {
+        let json = envelope::read(ctx, &format!("{}.json", code)).ok()??;
+        json["disasters"].as_array().map(Vec::len)
+    };
+    let sources = health::source_status(ctx.health().attempts(), &previous, incident_count);
+    let failed = sources.values().filter(|source| source["ok"] == json!(false)).count();
+
+    let output = json!({
+        "generated_at": ctx.state().observed_at().to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
+        "source_count": sources.len(),
+        "failed_count": failed,
+        "sources": sources
+    });
+    ctx.write("status.json", serde_json::to_string_pretty(&output)?.as_bytes())?;
+
+    println!("ソースごとの取得状況が生成されました: {}（失敗{}件）", ctx.location("status.json"), failed);
+    Ok(())
+}
+
+/// 出動情報の状態ファイルを保存する関数
+///
+/// `get_all`で観測した出動情報の識別子と観測日時を、次回の実行へ引き継ぐために保存する。
+pub fn save_incident_state(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    let mut state = ctx.state();
+    state.save()?;
+    if let Some(path) = state.path() {
+        println!("出動情報の状態ファイルを保存しました: {}（{}件）", path.display(), state.len());
+    }
+    Ok(())
+}
+
+/// 今回の実行の結果を、SQLiteのデータベースへ書き込む関数（`sqlite`フィーチャーを有効にした場合のみ使用できる）
+///
+/// データベースの場所は`RunContext::with_database_path`で指定したものとする（`RunContext::from_env`では
+/// 環境変数`EMERGENCY_DISPATCH_DATABASE`、無ければ`state/emergency_dispatch.sqlite3`）。
+#[cfg(feature = "sqlite")]
+pub fn save_database(ctx: &RunContext, failed_parser_count: usize) -> Result<(), Box<dyn std::error::Error>> {
+    let path = ctx.database_path().ok_or("データベースの場所が指定されていません")?;
+    let mut connection = database::open(path)?;
+    let observed_at = ctx.state().observed_at();
+    let run_id = database::record_run(ctx, &mut connection, &get_all_json(ctx)?, observed_at, failed_parser_count)?;
+    println!("データベースへ実行結果を保存しました: {}（実行番号{}）", path.display(), run_id);
+    Ok(())
 }
 
 /// キャッシュをクリアする
@@ -687,3 +1044,34 @@ pub fn clear_source_cache() {
     cache.clear();
     println!("ソースキャッシュをクリアしました（{}エントリ）", count);
 }
+
+/// 出力するファイルのJSON Schemaを出力先の`schema`に生成する関数
+pub fn generate_schema_json(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    for (name, document) in schema::documents() {
+        let path = format!("{}/{}.schema.json", schema::SCHEMA_DIR, name);
+        ctx.write(&path, serde_json::to_string_pretty(&document)?.as_bytes())?;
+    }
+    println!("JSON Schemaが生成されました: {}", ctx.location(&format!("{}/", schema::SCHEMA_DIR)));
+    Ok(())
+}
+
+/// 出力先に書き出したファイルを検証する関数
+///
+/// 市区町村ごとのファイルは`save_disaster_json`で書き出す前に検証しているため、ここで見つかる問題は`all.json`などの集約したファイルや構文の問題となる。
+/// 問題が見つかった場合は全て表示した上で、件数をエラーとして返す。
+pub fn validate_output(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    let problems = schema::validate_output(ctx)?;
+    for problem in &problems {
+        eprintln!("  [出力検証] {}", problem);
+    }
+    if !problems.is_empty() {
+        return Err(format!("出力されたファイルに{}件の問題があります", problems.len()).into());
+    }
+    println!("出力されたファイルの検証に成功しました: {}", ctx.location(""));
+    Ok(())
+}
+
+/// 出力先での後処理を行う関数（tarアーカイブへ出力する場合は、ここでアーカイブを書き出す）
+pub fn finish_output(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    ctx.finish()
+}
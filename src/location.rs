@@ -0,0 +1,250 @@
+use serde_json::{json, Value};
+
+/// 出動場所の種類ごとの構造化された情報
+///
+/// 通常の住所は`Address`となり、住所の構成要素は`address::parse_address`で別途分解する。
+/// 高速道路・鉄道・河川など、住所では表しきれない場所はそれぞれの種類で表す。
+#[derive(Debug, Clone, PartialEq)]
+pub enum Location {
+    /// 通常の住所
+    Address,
+    /// 道路（高速道路・国道など）
+    Road {
+        /// 路線名（例: 「名神高速道路」「国道1号」）
+        road_name: String,
+        /// 方向（例: 「上り」「下り」「外回り」）
+        direction: Option<String>,
+        /// キロポスト
+        kilometre_post: Option<f64>,
+        /// インターチェンジ・ジャンクションなどの名称（記載順）
+        interchanges: Vec<String>,
+    },
+    /// 鉄道
+    Railway {
+        /// 路線名（例: 「JR東海道本線」）
+        line: Option<String>,
+        /// 駅名（記載順）
+        stations: Vec<String>,
+    },
+    /// 河川
+    River {
+        /// 河川名
+        river: String,
+    },
+}
+
+impl Location {
+    /// 全ての種類の識別子
+    pub const KINDS: [&'static str; 4] = ["address", "road", "railway", "river"];
+
+    /// 出力に使用する種類の識別子
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Location::Address => "address",
+            Location::Road { .. } => "road",
+            Location::Railway { .. } => "railway",
+            Location::River { .. } => "river",
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        match self {
+            Location::Address => json!({ "kind": self.kind() }),
+            Location::Road { road_name, direction, kilometre_post, interchanges } => json!({
+                "kind": self.kind(),
+                "road_name": road_name,
+                "direction": direction,
+                "kilometre_post": kilometre_post,
+                "interchanges": interchanges
+            }),
+            Location::Railway { line, stations } => json!({
+                "kind": self.kind(),
+                "line": line,
+                "stations": stations
+            }),
+            Location::River { river } => json!({
+                "kind": self.kind(),
+                "river": river
+            }),
+        }
+    }
+}
+
+lazy_static::lazy_static! {
+    /// 路線名（国道・都道府県道の番号、首都高速などの路線、名称の付いた高速道路・自動車道・有料道路・バイパス）
+    ///
+    /// 名称には区市町村名や番地が含まれないものとし、住所の後に続く路線名を住所ごと取り込まないようにする。
+    static ref ROAD_RE: regex::Regex = regex::Regex::new(
+        r"国道\d+号線?|[都道府県]道\d+号線?|(?:首都|阪神|名古屋|福岡|北九州|広島)高速(?:道路)?\d*号?[^\s、。（(]*?線|[^\s、。（(の区市町村丁目番地号\d]{2,10}?(?:高速道路|自動車道|有料道路|バイパス)"
+    ).unwrap();
+    static ref DIRECTION_RE: regex::Regex = regex::Regex::new(r"(上り|下り|外回り|内回り|[東西南北]行き)線?").unwrap();
+    static ref KILOMETRE_POST_RE: regex::Regex = regex::Regex::new(
+        r"(\d+(?:\.\d+)?)\s*(?:キロポスト|KP|kp|km|キロ)"
+    ).unwrap();
+    static ref INTERCHANGE_RE: regex::Regex = regex::Regex::new(
+        r"[^\s]+?(?:インターチェンジ|ジャンクション|サービスエリア|パーキングエリア|料金所|インター|IC|JCT|SA|PA|ＩＣ|ＪＣＴ|ＳＡ|ＰＡ)"
+    ).unwrap();
+    static ref RAILWAY_LINE_RE: regex::Regex = regex::Regex::new(r"[^\s、。]*?線").unwrap();
+    static ref STATION_RE: regex::Regex = regex::Regex::new(r"([^\s、。（(区\d]+?駅)(.?)").unwrap();
+    static ref RIVER_RE: regex::Regex = regex::Regex::new(r"([^\s、。]+?川)(?:河川敷|河川|川岸|の|で|に|付近)").unwrap();
+}
+
+/// 「駅前」「駅南」のように、駅に続けて町名となる文字
+const STATION_TOWN_SUFFIXES: [char; 8] = ['前', '南', '北', '東', '西', '裏', '通', '元'];
+
+/// 鉄道上の場所であることを表す語（駅名が無くても鉄道として扱う）
+const RAILWAY_KEYWORDS: [&str; 5] = ["鉄道", "線路", "踏切", "JR", "電鉄"];
+
+/// 名称の区切りとして扱う語
+const SEPARATORS: [&str; 10] = ["から", "～", "~", "間", "付近", "と", "、", "の", "で", "において"];
+
+/// 区切りとなる語を空白に置き換え、名称を取り出しやすくする
+fn split_names(text: &str) -> String {
+    SEPARATORS
+        .iter()
+        .fold(text.to_string(), |acc, separator| acc.replace(separator, " "))
+}
+
+/// 道路上の場所を解析する
+fn parse_road(text: &str) -> Option<Location> {
+    let road = ROAD_RE.find(text)?;
+    let rest = &text[road.end()..];
+
+    let direction = DIRECTION_RE.captures(rest).map(|caps| caps[1].to_string());
+    let kilometre_post = KILOMETRE_POST_RE
+        .captures(rest)
+        .and_then(|caps| caps[1].parse().ok());
+
+    let rest = DIRECTION_RE.replace_all(rest, " ");
+    let rest = KILOMETRE_POST_RE.replace_all(&rest, " ");
+    let names = split_names(&rest);
+    let interchanges = INTERCHANGE_RE
+        .find_iter(&names)
+        .map(|m| m.as_str().to_string())
+        .collect();
+
+    Some(Location::Road {
+        road_name: road.as_str().to_string(),
+        direction,
+        kilometre_post,
+        interchanges,
+    })
+}
+
+/// 駅名を記載順に取り出す（「駅前」「駅南」などの町名は駅名とみなさない）
+fn find_stations(text: &str) -> Vec<String> {
+    STATION_RE
+        .captures_iter(&split_names(text))
+        .filter(|caps| !caps[2].starts_with(STATION_TOWN_SUFFIXES))
+        .map(|caps| caps[1].to_string())
+        .collect()
+}
+
+/// 鉄道上の場所を解析する
+///
+/// 鉄道を表す語か駅名のいずれかが無い場合は鉄道とみなさない。
+fn parse_railway(text: &str) -> Option<Location> {
+    let has_keyword = RAILWAY_KEYWORDS.iter().any(|keyword| text.contains(keyword));
+    if !has_keyword && find_stations(text).is_empty() {
+        return None;
+    }
+    let line = RAILWAY_LINE_RE.find(text);
+    let stations = find_stations(&line.map_or(text.to_string(), |m| text.replacen(m.as_str(), " ", 1)));
+    let line = line.map(|m| m.as_str().to_string());
+    if line.is_none() && stations.is_empty() {
+        return None;
+    }
+    Some(Location::Railway { line, stations })
+}
+
+/// 河川上の場所を解析する
+fn parse_river(text: &str) -> Option<Location> {
+    if !["河川", "川岸"].iter().any(|keyword| text.contains(keyword)) {
+        return None;
+    }
+    let caps = RIVER_RE.captures(text)?;
+    Some(Location::River { river: caps[1].to_string() })
+}
+
+/// 出動場所の文字列から、道路・鉄道・河川などの構造化された場所を取り出す関数
+///
+/// いずれにも当てはまらない場合は通常の住所（`Location::Address`）とみなす。
+pub fn parse_location(text: &str) -> Location {
+    parse_road(text)
+        .or_else(|| parse_railway(text))
+        .or_else(|| parse_river(text))
+        .unwrap_or(Location::Address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_expressway_with_direction_and_interchange() {
+        assert_eq!(
+            parse_location("名神高速道路上り線35.2キロポスト京都東IC付近"),
+            Location::Road {
+                road_name: "名神高速道路".to_string(),
+                direction: Some("上り".to_string()),
+                kilometre_post: Some(35.2),
+                interchanges: vec!["京都東IC".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn parses_expressway_after_municipality() {
+        let parsed = crate::address::parse_address("京都府京都市名神高速道路下り線京都東IC付近", Some("261009"));
+        assert_eq!(parsed.municipality.as_deref(), Some("京都市"));
+        match parse_location(&parsed.local_part()) {
+            Location::Road { road_name, direction, interchanges, .. } => {
+                assert_eq!(road_name, "名神高速道路");
+                assert_eq!(direction.as_deref(), Some("下り"));
+                assert_eq!(interchanges, vec!["京都東IC".to_string()]);
+            }
+            location => panic!("{:?}", location),
+        }
+    }
+
+    #[test]
+    fn parses_named_roads() {
+        for (text, road_name) in [
+            ("国道1号下り", "国道1号"),
+            ("県道24号線", "県道24号"),
+            ("新湘南バイパス", "新湘南バイパス"),
+            ("京都縦貫自動車道", "京都縦貫自動車道"),
+            ("首都高速3号渋谷線", "首都高速3号渋谷線"),
+        ] {
+            match parse_location(text) {
+                Location::Road { road_name: name, .. } => assert!(name.starts_with(road_name), "{}: {}", text, name),
+                location => panic!("{}: {:?}", text, location),
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_addresses_mentioning_roads_as_addresses() {
+        assert_eq!(parse_location("本町道路沿い"), Location::Address);
+        assert_eq!(parse_location("大宅道路沿い"), Location::Address);
+        assert_eq!(parse_location("バイパス通り1丁目"), Location::Address);
+    }
+
+    #[test]
+    fn parses_railway_with_line_and_stations() {
+        assert_eq!(
+            parse_location("JR東海道本線山科駅から京都駅間"),
+            Location::Railway {
+                line: Some("JR東海道本線".to_string()),
+                stations: vec!["山科駅".to_string(), "京都駅".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn keeps_station_area_towns_as_addresses() {
+        assert_eq!(parse_location("駅前1丁目"), Location::Address);
+        assert_eq!(parse_location("駅南2丁目3番"), Location::Address);
+        assert_eq!(parse_location("山科駅前"), Location::Address);
+    }
+}
@@ -1,22 +1,57 @@
 use emergency_dispatch::get_all;
 use emergency_dispatch::generate_list_json;
+use emergency_dispatch::collect_municipality_data;
 use emergency_dispatch::generate_all_json;
-use emergency_dispatch::generate_rss_feed;
+use emergency_dispatch::generate_department_json;
+use emergency_dispatch::generate_events_json;
+use emergency_dispatch::generate_geojson;
+use emergency_dispatch::generate_stats_json;
+use emergency_dispatch::generate_status_json;
+use emergency_dispatch::generate_feeds;
+use emergency_dispatch::generate_schema_json;
+use emergency_dispatch::generate_unattributed_json;
+use emergency_dispatch::mark_failed_sources;
+use emergency_dispatch::save_incident_state;
+use emergency_dispatch::validate_output;
 use emergency_dispatch::clear_source_cache;
-use std::fs;
+use emergency_dispatch::finish_output;
+use emergency_dispatch::RunContext;
 
 fn main() {
     println!("This software is emergency_dispatch. Developed by oageo.");
     println!("This software is released under the Apache 2.0 license and source code is available at https://github.com/oageo/emergency-dispatch.");
-    // distディレクトリが存在しない場合は作成
-    if let Err(e) = fs::create_dir_all("dist") {
-        eprintln!("「dist」ディレクトリの作成に失敗しました: {}", e);
-        return;
-    }
-    get_all().expect("データの取得に失敗しました");
-    generate_list_json().expect("対応している地方公共団体コードの一覧の生成に失敗しました");
-    generate_all_json().expect("統合災害情報ファイルの生成に失敗しました");
-    generate_rss_feed().expect("RSSフィードの生成に失敗しました");
+    // 出力先・状態ファイル・履歴などは、環境変数で指定された場所（無ければ作業ディレクトリの既定の場所）とする
+    let ctx = RunContext::from_env().expect("出力先の準備に失敗しました");
+    let failed_parser_count = get_all(&ctx).unwrap_or_else(|e| {
+        // 全てのソースの取得に失敗した場合も、古い出動情報を更新し、監視できるよう取得状況は書き出す
+        mark_failed_sources(&ctx).expect("取得に失敗したソースの更新に失敗しました");
+        generate_status_json(&ctx).expect("ソースごとの取得状況の生成に失敗しました");
+        finish_output(&ctx).expect("出力先での後処理に失敗しました");
+        panic!("データの取得に失敗しました: {}", e);
+    });
+    mark_failed_sources(&ctx).expect("取得に失敗したソースの更新に失敗しました");
+    generate_status_json(&ctx).expect("ソースごとの取得状況の生成に失敗しました");
+    generate_events_json(&ctx).expect("前回の実行からの変化の生成に失敗しました");
+    save_incident_state(&ctx).expect("出動情報の状態ファイルの保存に失敗しました");
+    generate_unattributed_json(&ctx).expect("振り分けられなかった出動情報の生成に失敗しました");
+    generate_list_json(&ctx).expect("対応している地方公共団体コードの一覧の生成に失敗しました");
+    // 統合災害情報ファイルと出動件数の集計は、市区町村ごとのファイルを1度だけ読み込んだ結果から生成する
+    let municipalities = collect_municipality_data(&ctx).expect("市区町村ごとのファイルの読み込みに失敗しました");
+    generate_all_json(&ctx, &municipalities).expect("統合災害情報ファイルの生成に失敗しました");
+    generate_stats_json(&ctx, &municipalities).expect("出動件数の集計の生成に失敗しました");
+    generate_department_json(&ctx).expect("消防本部ごとの出動情報の生成に失敗しました");
+    generate_geojson(&ctx).expect("GeoJSONファイルの生成に失敗しました");
+    generate_feeds(&ctx).expect("フィードの生成に失敗しました");
+    generate_schema_json(&ctx).expect("JSON Schemaの生成に失敗しました");
+    // 市区町村ごとのファイルはソースごとに検証済みのため、集約したファイルや構文に問題がある場合のみ実行を失敗として終了する
+    validate_output(&ctx).expect("出力されたファイルの検証に失敗しました");
+    finish_output(&ctx).expect("出力先での後処理に失敗しました");
+
+    // SQLiteのデータベースへの保存は、`sqlite`フィーチャーを有効にした場合のみ行う
+    #[cfg(feature = "sqlite")]
+    emergency_dispatch::save_database(&ctx, failed_parser_count).expect("データベースへの保存に失敗しました");
+    #[cfg(not(feature = "sqlite"))]
+    let _ = failed_parser_count;
 
     // キャッシュを明示的にクリア
     clear_source_cache();
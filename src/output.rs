@@ -0,0 +1,237 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// 出力先の既定の場所
+pub const DEFAULT_OUTPUT_DIR: &str = "dist";
+
+/// 出力先を指定する環境変数（`.tar`で終わる場合はtarアーカイブへ出力する）
+pub const OUTPUT_DIR_ENV: &str = "EMERGENCY_DISPATCH_OUTPUT";
+
+/// 出力先の場所（環境変数`EMERGENCY_DISPATCH_OUTPUT`、無ければ`dist`）
+pub fn output_dir() -> PathBuf {
+    PathBuf::from(std::env::var(OUTPUT_DIR_ENV).unwrap_or_else(|_| DEFAULT_OUTPUT_DIR.to_string()))
+}
+
+/// 生成したファイルの出力先
+///
+/// パスは出力先からの相対位置を`/`区切りで表したもの（例: `011002.json`、`feeds/index.json`）とする。
+/// 前回の実行の出力を引き継ぐ処理があるため、出力先は書き込みに加えて読み込みにも対応する。
+pub trait OutputSink: Send {
+    /// ファイルを書き出す
+    fn write(&mut self, path: &str, contents: &[u8]) -> Result<(), Box<dyn std::error::Error>>;
+
+    /// ファイルを読み込む（ファイルが無い場合は`None`）
+    fn read(&self, path: &str) -> Result<Option<Vec<u8>>, Box<dyn std::error::Error>>;
+
+    /// 全てのファイルのパスをパスの順に返す
+    fn list(&self) -> Result<Vec<String>, Box<dyn std::error::Error>>;
+
+    /// 表示用のファイルの場所
+    fn location(&self, path: &str) -> String;
+
+    /// 全てのファイルを書き出した後に呼び出す
+    fn finish(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+}
+
+/// ディレクトリへの出力
+pub struct DirectorySink {
+    dir: PathBuf,
+}
+
+impl DirectorySink {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        DirectorySink { dir: dir.into() }
+    }
+}
+
+impl OutputSink for DirectorySink {
+    fn write(&mut self, path: &str, contents: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+        let path = self.dir.join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        write_atomic(path, contents)
+    }
+
+    fn read(&self, path: &str) -> Result<Option<Vec<u8>>, Box<dyn std::error::Error>> {
+        match fs::read(self.dir.join(path)) {
+            Ok(contents) => Ok(Some(contents)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn list(&self) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let mut paths = vec![];
+        if self.dir.is_dir() {
+            list_dir(&self.dir, "", &mut paths)?;
+        }
+        paths.sort();
+        Ok(paths)
+    }
+
+    fn location(&self, path: &str) -> String {
+        self.dir.join(path).display().to_string()
+    }
+}
+
+/// ディレクトリ内のファイルを、サブディレクトリも含めて相対パスで列挙する（書き出し途中の一時ファイルは除く）
+fn list_dir(dir: &Path, prefix: &str, paths: &mut Vec<String>) -> Result<(), Box<dyn std::error::Error>> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if name.starts_with('.') {
+            continue;
+        }
+        let path = format!("{}{}", prefix, name);
+        if entry.file_type()?.is_dir() {
+            list_dir(&entry.path(), &format!("{}/", path), paths)?;
+        } else {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// メモリ上への出力
+///
+/// 複製したものは同じファイルを共有するため、出力先に設定する前に複製しておけば、実行後に内容を取り出せる。
+#[derive(Clone, Default)]
+pub struct MemorySink {
+    files: Arc<Mutex<BTreeMap<String, Vec<u8>>>>,
+}
+
+impl MemorySink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 書き出されたファイルをパスの順に返す
+    pub fn files(&self) -> BTreeMap<String, Vec<u8>> {
+        self.files.lock().unwrap().clone()
+    }
+}
+
+impl OutputSink for MemorySink {
+    fn write(&mut self, path: &str, contents: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+        self.files.lock().unwrap().insert(path.to_string(), contents.to_vec());
+        Ok(())
+    }
+
+    fn read(&self, path: &str) -> Result<Option<Vec<u8>>, Box<dyn std::error::Error>> {
+        Ok(self.files.lock().unwrap().get(path).cloned())
+    }
+
+    fn list(&self) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        Ok(self.files.lock().unwrap().keys().cloned().collect())
+    }
+
+    fn location(&self, path: &str) -> String {
+        format!("memory:{}", path)
+    }
+}
+
+/// tarアーカイブへの出力
+///
+/// 既存のアーカイブの内容を読み込んだ上でメモリ上に書き出し、`finish`で1つのアーカイブとしてまとめて置き換える。
+/// そのため、アーカイブを読み込む側からは、常にいずれかの実行の出力が揃った状態で見える。
+pub struct TarSink {
+    path: PathBuf,
+    files: MemorySink,
+}
+
+impl TarSink {
+    /// アーカイブを開く（アーカイブが無い場合は空の状態から始める）
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, Box<dyn std::error::Error>> {
+        let path = path.into();
+        let mut files = MemorySink::new();
+        if path.exists() {
+            let mut archive = tar::Archive::new(fs::File::open(&path)?);
+            for entry in archive.entries()? {
+                let mut entry = entry?;
+                if !entry.header().entry_type().is_file() {
+                    continue;
+                }
+                let name = entry.path()?.to_string_lossy().into_owned();
+                let mut contents = vec![];
+                entry.read_to_end(&mut contents)?;
+                files.write(&name, &contents)?;
+            }
+        }
+        Ok(TarSink { path, files })
+    }
+}
+
+impl OutputSink for TarSink {
+    fn write(&mut self, path: &str, contents: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+        self.files.write(path, contents)
+    }
+
+    fn read(&self, path: &str) -> Result<Option<Vec<u8>>, Box<dyn std::error::Error>> {
+        self.files.read(path)
+    }
+
+    fn list(&self) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        self.files.list()
+    }
+
+    fn location(&self, path: &str) -> String {
+        format!("{}:{}", self.path.display(), path)
+    }
+
+    fn finish(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        let mtime = chrono::Utc::now().timestamp().max(0) as u64;
+        let mut builder = tar::Builder::new(vec![]);
+        for (path, contents) in self.files.files() {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o644);
+            header.set_mtime(mtime);
+            header.set_cksum();
+            builder.append_data(&mut header, &path, contents.as_slice())?;
+        }
+        if let Some(parent) = self.path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        write_atomic(&self.path, builder.into_inner()?)
+    }
+}
+
+/// 出力先の場所から出力先を作成する関数（`.tar`で終わる場合はtarアーカイブ、それ以外はディレクトリ）
+pub fn sink_for(path: &Path) -> Result<Box<dyn OutputSink>, Box<dyn std::error::Error>> {
+    if path.extension().is_some_and(|extension| extension == "tar") {
+        Ok(Box::new(TarSink::open(path)?))
+    } else {
+        Ok(Box::new(DirectorySink::new(path)))
+    }
+}
+
+/// ファイルを原子的に書き出す関数
+///
+/// 同じディレクトリの一時ファイルへ書き込んでから名前を変更するため、書き出しの途中で読み込まれても、
+/// 読み込む側からは以前の内容か新しい内容のどちらかが見え、途中までしか書かれていないファイルが見えることは無い。
+pub fn write_atomic(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> Result<(), Box<dyn std::error::Error>> {
+    let path = path.as_ref();
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| format!("ファイル名がありません: {}", path.display()))?
+        .to_string_lossy();
+    let temporary = path.with_file_name(format!(".{}.{}.tmp", file_name, std::process::id()));
+
+    let result = (|| -> Result<(), Box<dyn std::error::Error>> {
+        let mut file = fs::File::create(&temporary)?;
+        file.write_all(contents.as_ref())?;
+        file.sync_all()?;
+        fs::rename(&temporary, path)?;
+        Ok(())
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&temporary);
+    }
+    result
+}
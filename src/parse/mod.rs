@@ -6,117 +6,59 @@ pub mod parse_012246;
 pub mod parse_012319;
 pub mod parse_012343;
 pub mod parse_022098;
-pub mod parse_062049;
 pub mod parse_062103;
-pub mod parse_064289;
-pub mod parse_064611;
-pub mod parse_072036;
-pub mod parse_072117;
-pub mod parse_075213;
-pub mod parse_075221;
 pub mod parse_082031;
 pub mod parse_083020;
 pub mod parse_092011;
 pub mod parse_112038;
-pub mod parse_112089;
-pub mod parse_112097;
-pub mod parse_112127;
-pub mod parse_112151;
-pub mod parse_112259;
-pub mod parse_112399;
-pub mod parse_112411;
-pub mod parse_112429;
-pub mod parse_113263;
-pub mod parse_113271;
-pub mod parse_113417;
-pub mod parse_113425;
-pub mod parse_113433;
-pub mod parse_113476;
-pub mod parse_113484;
-pub mod parse_113492;
-pub mod parse_113697;
 pub mod parse_121002;
 pub mod parse_122025;
 pub mod parse_122033;
-pub mod parse_122050;
 pub mod parse_122068;
-pub mod parse_122106;
-pub mod parse_122114;
-pub mod parse_122122;
-pub mod parse_122131;
 pub mod parse_122157;
 pub mod parse_122173;
-pub mod parse_122181;
 pub mod parse_122190;
-pub mod parse_122238;
 pub mod parse_122254;
 pub mod parse_122262;
 pub mod parse_122289;
 pub mod parse_122297;
-pub mod parse_122301;
-pub mod parse_122319;
-pub mod parse_122327;
 pub mod parse_122335;
-pub mod parse_122343;
-pub mod parse_122351;
-pub mod parse_122360;
-pub mod parse_122378;
-pub mod parse_122386;
-pub mod parse_122394;
-pub mod parse_123013;
 pub mod parse_123293;
-pub mod parse_123421;
-pub mod parse_123471;
-pub mod parse_123498;
-pub mod parse_124036;
-pub mod parse_124095;
-pub mod parse_124109;
-pub mod parse_124214;
-pub mod parse_124222;
-pub mod parse_124231;
-pub mod parse_124249;
-pub mod parse_124265;
-pub mod parse_124273;
-pub mod parse_124419;
-pub mod parse_124435;
-pub mod parse_124630;
 pub mod parse_141003;
 pub mod parse_141305;
-pub mod parse_142018;
-pub mod parse_142107;
 pub mod parse_151009;
 pub mod parse_152021;
-pub mod parse_152137;
-pub mod parse_153427;
 pub mod parse_172031;
 pub mod parse_231002;
 pub mod parse_232068;
 pub mod parse_261009;
-pub mod parse_272141;
-pub mod parse_272167;
-pub mod parse_272213;
-pub mod parse_272230;
-pub mod parse_272264;
 pub mod parse_272272;
-pub mod parse_273813;
-pub mod parse_273821;
-pub mod parse_273830;
 pub mod parse_282189;
 pub mod parse_292010;
 pub mod parse_292095;
 pub mod parse_322016;
-pub mod parse_342033;
-pub mod parse_342122;
-pub mod parse_344311;
-pub mod parse_352047;
-pub mod parse_355020;
 pub mod parse_401005;
 pub mod parse_401307;
-pub mod parse_402231;
-pub mod parse_403458;
-pub mod parse_412015;
-pub mod parse_412040;
-pub mod parse_412082;
-pub mod parse_412104;
-pub mod parse_413275;
+pub mod parse_awa;
+pub mod parse_chosei;
+pub mod parse_hagi;
+pub mod parse_higashihiroshima;
+pub mod parse_hiki;
+pub mod parse_inzai;
+pub mod parse_isumi;
+pub mod parse_kasuya_hokubu;
+pub mod parse_katori;
+pub mod parse_koriyama_chiho;
+pub mod parse_narita;
+pub mod parse_nishiiruma;
+pub mod parse_osaka_minami;
 pub mod parse_osumiakarijp;
+pub mod parse_saga_koiki;
+pub mod parse_saitama_seibu;
+pub mod parse_sakado_tsurugashima;
+pub mod parse_sakata_chiku;
+pub mod parse_sakura_yachimata_shisui;
+pub mod parse_sanbu;
+pub mod parse_sosa_yokoshibahikari;
+pub mod parse_tsubame_yahiko;
+pub mod parse_yokosuka;
@@ -1,9 +1,8 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
+use crate::units::extract_detail;
 use crate::to_half_width;
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "tomakomai119.ec-net.jp";
 const GET_SOURCE: &str = "http://tomakomai119.ec-net.jp/csv/fireguidance1_0.html";
@@ -14,7 +13,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_012131() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_012131(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("012131, 苫小牧市消防本部");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -93,7 +92,8 @@ pub fn return_012131() -> Result<(), Box<dyn std::error::Error>> {
                         disaster_data.push(json!({
                             "type": disaster_type,
                             "address": address,
-                            "time": time
+                            "time": time,
+                            "detail": extract_detail(type_part, &disaster_type)
                         }));
                     }
                 }
@@ -113,8 +113,7 @@ pub fn return_012131() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // JSONファイルに書き出し
-    let mut file = File::create("dist/012131.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 012131.json （苫小牧市消防本部）");
     Ok(())
@@ -1,8 +1,6 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "www.119.city.sapporo.jp";
 const GET_SOURCE: &str = "https://www.119.city.sapporo.jp/saigai/03/index.html";
@@ -12,7 +10,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_012246() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_012246(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("012246, 千歳市消防本部");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -73,8 +71,7 @@ pub fn return_012246() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // JSONファイルに書き出し
-    let mut file = File::create("dist/012246.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 012246.json （千歳市消防本部）");
     Ok(())
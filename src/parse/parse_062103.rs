@@ -1,8 +1,6 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
 
-use super::super::{HttpRequestConfig, get_source_with_config};
+use super::super::{HttpRequestConfig, RunContext, get_source_with_config, save_disaster_json};
 
 const HOST: &str = "tendo-shoubou.jp";
 const GET_SOURCE: &str = "http://tendo-shoubou.jp/index.html";
@@ -12,7 +10,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_062103() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_062103(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("062103, 天童市消防本部");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -66,8 +64,7 @@ pub fn return_062103() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // JSONファイルに書き出し
-    let mut file = File::create("dist/062103.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 062103.json （天童市消防本部）");
     Ok(())
@@ -1,9 +1,8 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
+use crate::units::extract_detail;
 use chrono::{Local, NaiveDateTime, Duration, TimeZone};
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "www.city.tsuchiura.lg.jp";
 const GET_SOURCE: &str = "https://www.city.tsuchiura.lg.jp/mm_pro/backnumber.php";
@@ -13,7 +12,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_082031() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_082031(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("082031, 土浦市消防本部");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -106,7 +105,8 @@ pub fn return_082031() -> Result<(), Box<dyn std::error::Error>> {
                         disaster_data.push(json!({
                             "type": disaster_type,
                             "address": address,
-                            "time": time
+                            "time": time,
+                            "detail": extract_detail(disaster_part, &disaster_type)
                         }));
                     }
                 }
@@ -126,8 +126,7 @@ pub fn return_082031() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // JSONファイルに書き出し
-    let mut file = File::create("dist/082031.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 082031.json （土浦市消防本部）");
     Ok(())
@@ -1,9 +1,8 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
+use crate::units::extract_detail;
 use crate::to_half_width; 
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "www.ibaraki-sirei.jp";
 const GET_SOURCE: &str = "http://www.ibaraki-sirei.jp/saigai/ibaraki/annai_list.html";
@@ -13,7 +12,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_083020() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_083020(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("083020, 茨城町消防本部");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -61,7 +60,8 @@ pub fn return_083020() -> Result<(), Box<dyn std::error::Error>> {
                 disaster_data.push(json!({
                     "type": disaster_type,
                     "address": address,
-                    "time": time
+                    "time": time,
+                    "detail": extract_detail(after.split("付近で").nth(1).unwrap_or(""), disaster_type)
                 }));
             }
         }
@@ -79,8 +79,7 @@ pub fn return_083020() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // JSONファイルに書き出し
-    let mut file = File::create("dist/083020.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 083020.json （茨城町消防本部）");
     Ok(())
@@ -1,15 +1,14 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
 use scraper::{Html, Selector};
 use crate::to_half_width;
+use crate::status::detect_status;
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "utsunomiya.mwjp.jp";
 const LIST_URL: &str = "https://utsunomiya.mwjp.jp/mobile/index.cgi?page=119";
 
-pub fn return_092011() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_092011(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("092011, 宇都宮市消防局");
 
     // ステップ1: 一覧ページを取得
@@ -17,22 +16,20 @@ pub fn return_092011() -> Result<(), Box<dyn std::error::Error>> {
     let list_body = get_source_with_config(&config)?;
     let list_document = Html::parse_document(&list_body);
 
-    // ステップ2: 一覧ページからリンクを抽出（「終了」を含まないもののみ）
+    // ステップ2: 一覧ページからリンクを抽出（「発生」または「終了」を含むもののみ）
     let link_selector = Selector::parse("a").unwrap();
     let mut disaster_data = vec![];
 
     for link_element in list_document.select(&link_selector) {
         let link_text = link_element.text().collect::<String>();
 
-        // 「終了」が含まれているリンクはスキップ
-        if link_text.contains("終了") {
+        // 「発生」または「終了」が含まれているリンクのみ処理
+        if !link_text.contains("発生") && !link_text.contains("終了") {
             continue;
         }
 
-        // 「発生」が含まれているリンクのみ処理
-        if !link_text.contains("発生") {
-            continue;
-        }
+        // 「終了」などから災害の状況を判定
+        let status = detect_status(&link_text);
 
         if let Some(href) = link_element.value().attr("href") {
             // 相対URLを絶対URLに変換
@@ -104,7 +101,8 @@ pub fn return_092011() -> Result<(), Box<dyn std::error::Error>> {
                     disaster_data.push(json!({
                         "type": disaster_type,
                         "address": address,
-                        "time": time
+                        "time": time,
+                        "status": status.as_str()
                     }));
                 },
                 Err(e) => {
@@ -126,8 +124,7 @@ pub fn return_092011() -> Result<(), Box<dyn std::error::Error>> {
         "disasters": disaster_data
     });
 
-    let mut file = File::create("dist/092011.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 092011.json （宇都宮市消防局）");
     Ok(())
@@ -1,15 +1,15 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
+use crate::units::extract_detail;
 use scraper::{Html, Selector};
 use crate::to_half_width;
+use crate::status::{detect_status, strip_status_phrase};
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "kawaguchi-city.mailio.jp";
 const LIST_URL: &str = "https://kawaguchi-city.mailio.jp/public/backnumber/ade3c8df79a74e889008f2736ff00f94";
 
-pub fn return_112038() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_112038(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("112038, 川口市消防局");
 
     // ステップ1: 一覧ページを取得
@@ -54,10 +54,8 @@ pub fn return_112038() -> Result<(), Box<dyn std::error::Error>> {
                             // 全角数字を半角数字に変換
                             let text = to_half_width(&text);
 
-                            // 「誤報」または「鎮火」が含まれている場合はスキップ
-                            if text.contains("誤報") || text.contains("鎮火") {
-                                continue;
-                            }
+                            // 「誤報」や「鎮火」などから災害の状況を判定
+                            let status = detect_status(&text);
 
                             // テキスト全体を改行やスペースを統一してから処理
                             let text = text.replace("\n", " ").replace("\r", " ");
@@ -104,11 +102,11 @@ pub fn return_112038() -> Result<(), Box<dyn std::error::Error>> {
                                         address
                                     };
 
-                                    // 災害種別を抽出（「が発生しました」以降を削除）
+                                    // 災害種別を抽出（「が発生しました」以降を削除。鎮火などの場合は状況を表す語の前まで）
                                     let disaster_type = if let Some((disaster, _)) = type_part.split_once("が発生し") {
-                                        disaster.trim().to_string()
+                                        strip_status_phrase(disaster).to_string()
                                     } else {
-                                        type_part.trim().to_string()
+                                        strip_status_phrase(type_part).to_string()
                                     };
 
                                     // 必要な情報がすべて揃っている場合のみ追加
@@ -116,7 +114,9 @@ pub fn return_112038() -> Result<(), Box<dyn std::error::Error>> {
                                         disaster_data.push(json!({
                                             "type": disaster_type,
                                             "address": address,
-                                            "time": time
+                                            "time": time,
+                                            "status": status.as_str(),
+                                            "detail": extract_detail(type_part, &disaster_type)
                                         }));
                                     }
                                 }
@@ -143,8 +143,7 @@ pub fn return_112038() -> Result<(), Box<dyn std::error::Error>> {
         "disasters": disaster_data
     });
 
-    let mut file = File::create("dist/112038.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 112038.json （川口市消防局）");
     Ok(())
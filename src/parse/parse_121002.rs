@@ -1,8 +1,8 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
 
-use super::super::{get_source_with_config, to_half_width, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, to_half_width, HttpRequestConfig, RunContext};
+use crate::status::{detect_status, IncidentStatus};
+use crate::units::extract_detail;
 
 const HOST: &str = "chb1018.hs.plala.or.jp";
 const GET_SOURCE: &str = "http://chb1018.hs.plala.or.jp/chiba119/Web/chiba/annai_list.html";
@@ -12,7 +12,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_121002() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_121002(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("121002, 千葉市（千葉市消防局）");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -26,10 +26,11 @@ pub fn return_121002() -> Result<(), Box<dyn std::error::Error>> {
         // 全角数字を半角数字に変換
         let text = to_half_width(&text);
 
-        // "情報" で災害種別を判定（誤報を除外）
-        if text.contains("情報") && !text.contains("ではありません") {
+        // "情報" で災害種別を判定（「…ではありません」は誤報として状況を判定する）
+        let status = detect_status(&text);
+        if text.contains("情報") {
             // フォーマット: "火災情報 2025年11月08日23時48分頃、千葉市中央区出洲港７番　サンクタス千葉シーサイドアベニュー付近で車両火災が発生しています。"
-            if let Some((_type_part, rest)) = text.split_once("情報") {
+            if let Some((type_part, rest)) = text.split_once("情報") {
 
                 // 日時と場所情報を分離
                 if let Some(date_location) = rest.split_once("頃、") {
@@ -48,7 +49,9 @@ pub fn return_121002() -> Result<(), Box<dyn std::error::Error>> {
                                 .to_string();
 
                             // 場所と災害詳細を分離（"千葉市中央区出洲港７番　サンクタス千葉シーサイドアベニュー付近で車両火災が発生しています。"）
-                            if let Some((address_part, disaster_detail)) = location_info.split_once("付近で") {
+                            // 「付近で」に続かない誤報の文面からも住所を取り出せるよう、「付近」で区切る
+                            if let Some((address_part, disaster_detail)) = location_info.split_once("付近") {
+                                let disaster_detail = disaster_detail.trim_start_matches(['で', 'の']);
                                 // 目標地点を除去（全角空白2つ（　）で区切られた後の部分）
                                 // 目標がない場合もあるので、全角空白がない場合はそのまま使用
                                 let address = if address_part.contains("　") {
@@ -70,12 +73,19 @@ pub fn return_121002() -> Result<(), Box<dyn std::error::Error>> {
                                     .trim();
 
                                 // 災害種別として使用
-                                let full_disaster_type = disaster_type_detail.to_string();
+                                // 誤報の場合は「火災情報」の「火災」などを災害種別とする
+                                let full_disaster_type = if status == IncidentStatus::FalseAlarm {
+                                    type_part.trim().to_string()
+                                } else {
+                                    disaster_type_detail.to_string()
+                                };
 
                                 disaster_data.push(json!({
                                     "type": full_disaster_type,
                                     "address": address,
-                                    "time": time
+                                    "time": time,
+                                    "status": status.as_str(),
+                                    "detail": extract_detail(disaster_detail, &full_disaster_type)
                                 }));
                             }
                         }
@@ -97,8 +107,7 @@ pub fn return_121002() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // JSONファイルに書き出し
-    let mut file = File::create("dist/121002.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 121002.json （千葉市・千葉市消防局）");
     Ok(())
@@ -1,9 +1,9 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
+use crate::units::extract_detail;
 use crate::to_half_width; 
+use crate::status::{detect_status, strip_status_phrase};
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "mama.city.ichikawa.chiba.jp";
 const GET_SOURCE: &str = "http://mama.city.ichikawa.chiba.jp/saigai/index.html";
@@ -13,7 +13,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_122033() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_122033(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("122033, 市川市消防局");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -24,11 +24,14 @@ pub fn return_122033() -> Result<(), Box<dyn std::error::Error>> {
     for element in document.select(&selector) {
         let text = to_half_width(&element.text().collect::<String>().trim().to_string()); // `to_half_width`を使用
 
-        // 「終了」、「鎮火」、または「只今、災害は発生しておりません。」が含まれている場合はスキップ
-        if text.contains("終了") || text.contains("鎮火") || text.contains("只今、災害は発生しておりません。") {
+        // 「只今、災害は発生しておりません。」が含まれている場合はスキップ
+        if text.contains("只今、災害は発生しておりません。") {
             continue;
         }
 
+        // 「終了」や「鎮火」などから災害の状況を判定
+        let status = detect_status(&text);
+
         // データを解析 - 実際のフォーマット: 「９月１４日午後４時１４分頃市川市南行徳２丁目７番付近で救急連携活動のため消防車が出場しています。」
         if let Some(time_part) = text.split("頃市川市").next() {
             if let Some(location_part) = text.split("頃市川市").nth(1) {
@@ -84,25 +87,26 @@ pub fn return_122033() -> Result<(), Box<dyn std::error::Error>> {
                         "00:00".to_string()
                     };
 
-                    // 災害タイプの抽出
-                    let disaster_type = type_part
-                        .split("が発生し")
-                        .next()
-                        .unwrap_or(type_part)
-                        .split("のため消防車が出場しています")
-                        .next()
-                        .unwrap_or(type_part)
-                        .trim()
-                        .replace("活動", "")
-                        .trim()
-                        .to_string();
+                    // 災害タイプの抽出（鎮火などの場合は状況を表す語の前まで）
+                    let disaster_type = strip_status_phrase(
+                        type_part
+                            .split("が発生し")
+                            .next()
+                            .unwrap_or(type_part)
+                            .split("のため消防車が出場しています")
+                            .next()
+                            .unwrap_or(type_part)
+                    )
+                    .to_string();
 
                     let address = format!("千葉県市川市{}", address.trim());
 
                     disaster_data.push(json!({
                         "type": disaster_type,
                         "address": address,
-                        "time": time
+                        "time": time,
+                        "status": status.as_str(),
+                        "detail": extract_detail(type_part, &disaster_type)
                     }));
                 }
             }
@@ -121,8 +125,7 @@ pub fn return_122033() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // JSONファイルに書き出し
-    let mut file = File::create("dist/122033.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 122033.json （市川市消防局）");
     Ok(())
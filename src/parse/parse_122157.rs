@@ -1,8 +1,8 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
 
-use super::super::{get_source_with_config, to_half_width, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, to_half_width, HttpRequestConfig, RunContext};
+use crate::status::{detect_status, IncidentStatus};
+use crate::units::extract_detail;
 
 const HOST: &str = "chb1018.hs.plala.or.jp";
 const GET_SOURCE: &str = "http://chb1018.hs.plala.or.jp/chiba119/Web/asahi/annai_list.html";
@@ -12,7 +12,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_122157() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_122157(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("122157, 旭市（旭市消防本部）");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -24,8 +24,9 @@ pub fn return_122157() -> Result<(), Box<dyn std::error::Error>> {
         let text = element.text().collect::<String>();
         let text = to_half_width(&text);
 
-        if text.contains("情報") && !text.contains("ではありません") {
-            if let Some((_type_part, rest)) = text.split_once("情報") {
+        let status = detect_status(&text);
+        if text.contains("情報") {
+            if let Some((type_part, rest)) = text.split_once("情報") {
 
                 if let Some(date_location) = rest.split_once("頃、") {
                     let date_time_str = date_location.0.trim();
@@ -40,7 +41,9 @@ pub fn return_122157() -> Result<(), Box<dyn std::error::Error>> {
                                 .trim()
                                 .to_string();
 
-                            if let Some((address_part, disaster_detail)) = location_info.split_once("付近で") {
+                            // 「付近で」に続かない誤報の文面からも住所を取り出せるよう、「付近」で区切る
+                            if let Some((address_part, disaster_detail)) = location_info.split_once("付近") {
+                                let disaster_detail = disaster_detail.trim_start_matches(['で', 'の']);
                                 let address = if address_part.contains("　") {
                                     address_part
                                         .split("　")
@@ -57,12 +60,19 @@ pub fn return_122157() -> Result<(), Box<dyn std::error::Error>> {
                                     .unwrap_or("")
                                     .trim();
 
-                                let full_disaster_type = disaster_type_detail.to_string();
+                                // 誤報の場合は「火災情報」の「火災」などを災害種別とする
+                                let full_disaster_type = if status == IncidentStatus::FalseAlarm {
+                                    type_part.trim().to_string()
+                                } else {
+                                    disaster_type_detail.to_string()
+                                };
 
                                 disaster_data.push(json!({
                                     "type": full_disaster_type,
                                     "address": address,
-                                    "time": time
+                                    "time": time,
+                                    "status": status.as_str(),
+                                    "detail": extract_detail(disaster_detail, &full_disaster_type)
                                 }));
                             }
                         }
@@ -83,8 +93,7 @@ pub fn return_122157() -> Result<(), Box<dyn std::error::Error>> {
         "disasters": disaster_data
     });
 
-    let mut file = File::create("dist/122157.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 122157.json （旭市・旭市消防本部）");
     Ok(())
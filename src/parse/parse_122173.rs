@@ -1,9 +1,9 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
+use crate::units::extract_detail;
 use crate::to_half_width; 
+use crate::status::{detect_status, strip_status_phrase};
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "www.city.kashiwa.lg.jp";
 const GET_SOURCE: &str = "https://www.city.kashiwa.lg.jp/fdk/disaster/index.html";
@@ -13,7 +13,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_122173() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_122173(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("122173, 柏市消防局");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -46,12 +46,15 @@ pub fn return_122173() -> Result<(), Box<dyn std::error::Error>> {
             .trim()
             .to_string();
 
-        // 「現在、管内で災害は発生しておりません」または「終了」、「鎮火」が含まれている場合はスキップ
-        if text.contains("現在、管内で災害は発生しておりません") || text.contains("終了") || text.contains("鎮火") {
+        // 「現在、管内で災害は発生しておりません」が含まれている場合はスキップ
+        if text.contains("現在、管内で災害は発生しておりません") {
             continue;
         }
         // データを解析
         else if let Some((location, after_de)) = text.split_once("で") {
+            // 「終了」や「鎮火」などから災害の状況を判定
+            let status = detect_status(&text);
+
             // address: 全角括弧が一組だけの場合に対応
             let mut addr = location
                 .split("ごろ柏市")
@@ -77,17 +80,19 @@ pub fn return_122173() -> Result<(), Box<dyn std::error::Error>> {
 
             let address = format!("千葉県柏市{}", addr);
 
-            // disaster_type: 「付近で」以降、「が発生」の間
+            // disaster_type: 「付近で」以降、「が発生」の間（鎮火などの場合は状況を表す語の前まで）
             let disaster_type = if let Some((ty, _)) = after_de.split_once("が発生") {
-                ty.trim().to_string()
+                strip_status_phrase(ty).to_string()
             } else {
-                after_de.trim().to_string()
+                strip_status_phrase(after_de).to_string()
             };
 
             disaster_data.push(json!({
                 "type": disaster_type,
                 "address": address,
-                "time": time
+                "time": time,
+                "status": status.as_str(),
+                "detail": extract_detail(after_de, &disaster_type)
             }));
         }
     }
@@ -103,9 +108,8 @@ pub fn return_122173() -> Result<(), Box<dyn std::error::Error>> {
         "disasters": disaster_data
     });
 
-    let mut file = File::create("dist/122173.json")?;
     // JSONファイルに書き出し
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 122173.json （柏市消防局）");
     Ok(())
@@ -1,9 +1,8 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
 use crate::to_half_width; 
+use crate::status::{detect_status, false_alarm_type, IncidentStatus};
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "chb1018.hs.plala.or.jp";
 const GET_SOURCE: &str = "http://chb1018.hs.plala.or.jp/chiba119/Web/ichihara/annai_list.html";
@@ -13,7 +12,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_122190() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_122190(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("122190, 市原市消防局");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -26,11 +25,13 @@ pub fn return_122190() -> Result<(), Box<dyn std::error::Error>> {
             .replace(' ', "")
             .trim()
             .to_string();
-        // 「必ず火の元の点検をしましょう」や誤報が含まれている場合はスキップ
-        if text.contains("必ず火の元の点検をしましょう") || text.contains("ではありません") {
+        // 「必ず火の元の点検をしましょう」が含まれている場合はスキップ（誤報は状況を`false_alarm`として出力する）
+        if text.contains("必ず火の元の点検をしましょう") {
             continue;
         }
         else if let Some((before, after)) = text.split_once("頃、市原市") {
+            let status = detect_status(&text);
+
             // 時刻
             let time = before.chars().rev().take(6).collect::<String>().chars().rev().collect::<String>()
                 .replace("時", ":").replace("分", "");
@@ -47,9 +48,11 @@ pub fn return_122190() -> Result<(), Box<dyn std::error::Error>> {
                 format!("市原市{}", after)
             };
 
-            // 災害種別（「消防隊が」以降、「活動」まで）
+            // 災害種別（「消防隊が」以降、「活動」まで。誤報の場合は打ち消された災害種別）
             let disaster_type = if let Some(rest) = after.split("消防隊が").nth(1) {
                 rest.split("活動").next().unwrap_or("").to_string()
+            } else if status == IncidentStatus::FalseAlarm {
+                false_alarm_type(after).unwrap_or_default().to_string()
             } else {
                 "".to_string()
             };
@@ -61,7 +64,8 @@ pub fn return_122190() -> Result<(), Box<dyn std::error::Error>> {
                 disaster_data.push(json!({
                     "type": disaster_type,
                     "address": address,
-                    "time": time
+                    "time": time,
+                    "status": status.as_str()
                 }));
             }
         }
@@ -79,8 +83,7 @@ pub fn return_122190() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // JSONファイルに書き出し
-    let mut file = File::create("dist/122190.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 122190.json （市原市消防局）");
     Ok(())
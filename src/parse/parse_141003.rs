@@ -1,9 +1,7 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
 use crate::to_half_width;
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "cgi.city.yokohama.lg.jp";
 const GET_SOURCE: &str = "https://cgi.city.yokohama.lg.jp/shobo/disaster/";
@@ -13,7 +11,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_141003() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_141003(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("141003, 横浜市消防局");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -99,8 +97,7 @@ pub fn return_141003() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // Write to JSON file
-    let mut file = File::create("dist/141003.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 141003.json （横浜市消防局）");
     Ok(())
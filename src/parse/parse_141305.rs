@@ -1,9 +1,8 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
 use crate::to_half_width;
+use crate::status::{detect_status, strip_status_phrase};
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "sc.city.kawasaki.jp";
 const GET_SOURCE: &str = "https://sc.city.kawasaki.jp/saigai/index.htm";
@@ -13,7 +12,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_141305() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_141305(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("141305, 川崎市消防局");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -30,13 +29,14 @@ pub fn return_141305() -> Result<(), Box<dyn std::error::Error>> {
 
         // スキップ条件
         if text.contains("市内に災害は発生しておりません")
-            || text.contains("完了しました")
-            || text.contains("終了しました")
             || text.contains("横浜市")
             || text.trim().is_empty() {
             continue;
         }
 
+        // 「完了しました」「終了しました」などから災害の状況を判定
+        let status = detect_status(&text);
+
         // 「頃」で分割して時刻部分を取得
         if let Some((time_part, rest)) = text.split_once("頃") {
             // 時刻を抽出（「M月D日　HH時MM分」→「HH:MM」）
@@ -66,18 +66,16 @@ pub fn return_141305() -> Result<(), Box<dyn std::error::Error>> {
             let (location, disaster_type) = if let Some((loc, dtype)) = rest.split_once("付近より") {
                 let dtype = dtype
                     .replace("の通報があり、消防車が出場しています", "")
-                    .replace("火災", "")
-                    .trim()
-                    .to_string();
+                    .replace("火災", "");
+                let dtype = strip_status_phrase(&dtype).to_string();
                 let dtype = if dtype.is_empty() { "火災".to_string() } else { dtype };
                 (loc.trim(), dtype)
             } else if let Some((loc, dtype)) = rest.split_once("付近で発生した") {
                 let dtype = dtype
-                    .split("は処理が完了しました")
+                    .split("は処理が")
                     .next()
-                    .unwrap_or("")
-                    .trim()
-                    .to_string();
+                    .unwrap_or("");
+                let dtype = strip_status_phrase(dtype).to_string();
                 (loc.trim(), dtype)
             } else {
                 continue;
@@ -99,7 +97,8 @@ pub fn return_141305() -> Result<(), Box<dyn std::error::Error>> {
                 disaster_data.push(json!({
                     "type": disaster_type,
                     "address": address,
-                    "time": time
+                    "time": time,
+                    "status": status.as_str()
                 }));
             }
         }
@@ -116,8 +115,7 @@ pub fn return_141305() -> Result<(), Box<dyn std::error::Error>> {
         "disasters": disaster_data
     });
 
-    let mut file = File::create("dist/141305.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 141305.json （川崎市消防局）");
     Ok(())
@@ -1,9 +1,8 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
+use crate::units::extract_detail;
 use crate::to_half_width; 
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "niigata119.city.niigata.lg.jp";
 const GET_SOURCE: &str = "https://niigata119.city.niigata.lg.jp/";
@@ -13,7 +12,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_151009() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_151009(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("151009, 新潟市消防局");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -53,7 +52,8 @@ pub fn return_151009() -> Result<(), Box<dyn std::error::Error>> {
                 disaster_data.push(json!({
                     "type": disaster_type,
                     "address": address,
-                    "time": time
+                    "time": time,
+                    "detail": extract_detail(reason, disaster_type)
                 }));
             }
         }
@@ -71,8 +71,7 @@ pub fn return_151009() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // JSONファイルに書き出し
-    let mut file = File::create("dist/151009.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 151009.json （新潟市消防局）");
     Ok(())
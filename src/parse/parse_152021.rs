@@ -1,9 +1,7 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
 use crate::to_half_width; 
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "www.nagaoka-fd.com";
 const GET_SOURCE: &str = "http://www.nagaoka-fd.com/fire/saigai/saigaipc.html";
@@ -13,7 +11,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_152021() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_152021(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("152021, 長岡市消防本部");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -71,8 +69,7 @@ pub fn return_152021() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // JSONファイルに書き出し
-    let mut file = File::create("dist/152021.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 152021.json （長岡市消防本部）");
     Ok(())
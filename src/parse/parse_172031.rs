@@ -1,9 +1,8 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
+use crate::units::extract_detail;
 use crate::to_half_width;
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "www.kfd119.jp";
 const GET_SOURCE: &str = "http://www.kfd119.jp/fire/saigai/saigaipc.html";
@@ -13,7 +12,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_172031() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_172031(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("172031, 小松市消防本部");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -52,7 +51,8 @@ pub fn return_172031() -> Result<(), Box<dyn std::error::Error>> {
                     disaster_data.push(json!({
                         "type": disaster_type,
                         "address": address,
-                        "time": time
+                        "time": time,
+                        "detail": extract_detail(disaster_info, disaster_type)
                     }));
                 }
             }
@@ -71,8 +71,7 @@ pub fn return_172031() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // JSONファイルに書き出し
-    let mut file = File::create("dist/172031.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 172031.json （小松市消防本部）");
     Ok(())
@@ -1,10 +1,10 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
+use crate::units::extract_detail;
 use crate::to_half_width;
+use crate::status::{detect_status, strip_status_phrase, IncidentStatus};
 use chrono::{DateTime, Local, Duration};
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "nagoya-fd.site2.ktaiwork.jp";
 const GET_SOURCE: &str = "https://nagoya-fd.site2.ktaiwork.jp/";
@@ -14,7 +14,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_231002() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_231002(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("231002, 名古屋市消防局");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -55,11 +55,6 @@ pub fn return_231002() -> Result<(), Box<dyn std::error::Error>> {
             .map(|element| element.text().collect::<String>().trim().to_string())
             .unwrap_or_default();
 
-        // 「火災鎮火」「火災誤報」の場合はスキップ
-        if title.contains("鎮火") || title.contains("誤報") {
-            continue;
-        }
-
         // entry-content内のテキストを取得
         let content_selector = scraper::Selector::parse("div.entry-content p").unwrap();
         let content = article
@@ -68,16 +63,17 @@ pub fn return_231002() -> Result<(), Box<dyn std::error::Error>> {
             .map(|element| to_half_width(&element.text().collect::<String>().trim().to_string()))
             .unwrap_or_default();
 
-        // 「鎮火」という文字列が本文に含まれている場合もスキップ
-        if content.contains("鎮火") {
-            continue;
-        }
-
         // 内容が空の場合はスキップ
         if content.is_empty() {
             continue;
         }
 
+        // 「火災鎮火」「火災誤報」などのタイトルや本文から災害の状況を判定
+        let status = match detect_status(&title) {
+            IncidentStatus::Dispatched => detect_status(&content),
+            status => status,
+        };
+
         // パース処理
         // 形式: "2025年09月30日12時38分頃、南区呼続４丁目　地内から火災の通報があり消防車が出動しています。"
 
@@ -118,14 +114,16 @@ pub fn return_231002() -> Result<(), Box<dyn std::error::Error>> {
                 .trim()
                 .to_string()
         } else {
-            // 将来の拡張のため、デフォルトはタイトルから推測
-            title.clone()
+            // 将来の拡張のため、デフォルトはタイトルから推測（「火災鎮火」などは状況を表す語を除く）
+            strip_status_phrase(&title).to_string()
         };
 
         disaster_data.push(json!({
             "type": disaster_type,
             "address": address,
-            "time": time
+            "time": time,
+            "status": status.as_str(),
+            "detail": extract_detail(content.split("から").nth(1).unwrap_or(""), &disaster_type)
         }));
     }
 
@@ -141,8 +139,7 @@ pub fn return_231002() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // JSONファイルに書き出し
-    let mut file = File::create("dist/231002.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 231002.json （名古屋市消防局）");
     Ok(())
@@ -1,8 +1,7 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
+use crate::units::extract_detail;
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "www.syobo.city.kasugai.aichi.jp";
 const GET_SOURCE: &str = "http://www.syobo.city.kasugai.aichi.jp/syobo/real/kasai.html";
@@ -12,7 +11,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_232068() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_232068(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("232068, 春日井市消防本部");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -55,7 +54,8 @@ pub fn return_232068() -> Result<(), Box<dyn std::error::Error>> {
                         disaster_data.push(json!({
                             "type": disaster_type,
                             "address": address,
-                            "time": time
+                            "time": time,
+                            "detail": extract_detail(disaster_part, &disaster_type)
                         }));
                     }
                 }
@@ -75,8 +75,7 @@ pub fn return_232068() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // JSONファイルに書き出し
-    let mut file = File::create("dist/232068.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 232068.json （春日井市消防本部）");
     Ok(())
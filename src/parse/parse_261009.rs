@@ -1,9 +1,7 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
 use crate::to_half_width; // 全角数字を半角数字に変換する関数
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "disif.city.kyoto.lg.jp";
 const GET_SOURCE: &str = "https://disif.city.kyoto.lg.jp/annai/main/";
@@ -13,7 +11,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_261009() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_261009(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("261009, 京都市消防局");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -37,13 +35,16 @@ pub fn return_261009() -> Result<(), Box<dyn std::error::Error>> {
                         if let Some((date_time, location_details)) = details.split_once("頃、") {
                             // 高速道路の場合と通常の住所の場合を分岐
                             let (address, disaster_type) = if location_details.contains("高速道路") {
-                                // 高速道路の場合
-                                let highway_address = location_details
+                                // 高速道路の場合は、路線名や方向などを`parse_location`で取り出せるよう場所をそのまま残す
+                                let highway_location = location_details
                                     .split("の災害に") // 「の災害に」以降を削除
                                     .next()
                                     .unwrap_or(location_details)
                                     .trim();
-                                (highway_address.to_string(), disaster_type.trim().to_string())
+                                (
+                                    format!("京都府京都市{}", highway_location),
+                                    disaster_type.trim().to_string(),
+                                )
                             } else if let Some((address, _)) = location_details.split_once("付近") {
                                 // 通常の住所の場合
                                 (
@@ -87,8 +88,7 @@ pub fn return_261009() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // JSONファイルに書き出し
-    let mut file = File::create("dist/261009.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 261009.json （京都市消防局）");
     Ok(())
@@ -1,9 +1,8 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
+use crate::units::extract_detail;
 use crate::to_half_width;
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "www.city.higashiosaka.lg.jp";
 const GET_SOURCE: &str = "https://www.city.higashiosaka.lg.jp/saigai/saigai.html";
@@ -13,7 +12,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_272272() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_272272(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("272272, 東大阪市消防局");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -67,7 +66,8 @@ pub fn return_272272() -> Result<(), Box<dyn std::error::Error>> {
                         disaster_data.push(json!({
                             "type": disaster_type,
                             "address": address,
-                            "time": time
+                            "time": time,
+                            "detail": extract_detail(type_part, &disaster_type)
                         }));
                     }
                 }
@@ -86,8 +86,7 @@ pub fn return_272272() -> Result<(), Box<dyn std::error::Error>> {
         "disasters": disaster_data
     });
 
-    let mut file = File::create("dist/272272.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 272272.json （東大阪市消防局）");
     Ok(())
@@ -1,8 +1,6 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "nara119.jp";
 const GET_SOURCE: &str = "https://nara119.jp/fire/saigai/saigaipcNara.html";
@@ -12,7 +10,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_292010() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_292010(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("292010, 奈良市消防局");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -62,8 +60,7 @@ pub fn return_292010() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // JSONファイルに書き出し
-    let mut file = File::create("dist/292010.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 292010.json （奈良市消防局）");
     Ok(())
@@ -1,9 +1,8 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
+use crate::units::extract_detail;
 use crate::to_half_width; 
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "www.m.shirei-saigai-jyouhou.sd.web-sanin.jp";
 const GET_SOURCE: &str = "https://www.m.shirei-saigai-jyouhou.sd.web-sanin.jp/index.html";
@@ -13,7 +12,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_322016() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_322016(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("322016, 松江市消防本部");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -70,7 +69,8 @@ pub fn return_322016() -> Result<(), Box<dyn std::error::Error>> {
             disaster_data.push(json!({
                 "type": disaster_type,
                 "address": address,
-                "time": time
+                "time": time,
+                "detail": extract_detail(reason, disaster_type)
             }));
         }
     }
@@ -87,8 +87,7 @@ pub fn return_322016() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // JSONファイルに書き出し
-    let mut file = File::create("dist/322016.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 322016.json （松江市消防本部）");
     Ok(())
@@ -1,9 +1,8 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
+use crate::units::extract_detail;
 use crate::to_half_width; 
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_disaster_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "kitakyushu.xpressmail.jp";
 const GET_SOURCE: &str = "http://kitakyushu.xpressmail.jp/saigai/navi/denbun.html";
@@ -13,7 +12,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_401005() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_401005(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("401005, 北九州市消防局");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -50,13 +49,13 @@ pub fn return_401005() -> Result<(), Box<dyn std::error::Error>> {
                     .trim()
                     .split("のため")
                     .next()
-                    .unwrap_or("") // 「のため」以降を削除
-                    .trim_end_matches("活動"); // 「活動」を削除
+                    .unwrap_or(""); // 「のため」以降を削除（種別の分類は出力時に行うため、「活動」などはそのまま残す）
 
                 disaster_data.push(json!({
                     "type": disaster_type,
                     "address": address,
-                    "time": time
+                    "time": time,
+                    "detail": extract_detail(reason, disaster_type)
                 }));
             }
         }
@@ -74,8 +73,7 @@ pub fn return_401005() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // JSONファイルに書き出し
-    let mut file = File::create("dist/401005.json")?;
-    file.write_all(output.to_string().as_bytes())?;
+    save_disaster_json(ctx, &output)?;
     eprintln!("{:?}", output);
     println!("JSONファイルが出力されました: 401005.json （北九州市消防局）");
     Ok(())
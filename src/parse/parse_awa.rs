@@ -0,0 +1,95 @@
+use serde_json::json;
+
+use super::super::{get_source_with_config, save_department_json, to_half_width, HttpRequestConfig, RunContext};
+use crate::status::{detect_status, IncidentStatus};
+use crate::units::extract_detail;
+
+const HOST: &str = "chb1018.hs.plala.or.jp";
+const GET_SOURCE: &str = "http://chb1018.hs.plala.or.jp/chiba119/Web/awa/annai_list.html";
+
+fn getsource() -> Result<String, Box<dyn std::error::Error>> {
+    let config = HttpRequestConfig::new(HOST, GET_SOURCE).with_shift_jis(true);
+    get_source_with_config(&config)
+}
+
+/// 安房郡市広域市町村圏事務組合消防本部の出動情報を取得し、構成市町村（館山市・鴨川市・南房総市・鋸南町）ごとに書き出す
+pub fn return_awa(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    println!("122050, 122238, 122343, 124630, 安房郡市広域市町村圏事務組合消防本部");
+    let body = getsource()?;
+    let document = scraper::Html::parse_document(&body);
+
+    let selector = scraper::Selector::parse("strong").unwrap();
+    let mut disaster_data = vec![];
+
+    for element in document.select(&selector) {
+        let text = element.text().collect::<String>();
+        let text = to_half_width(&text);
+
+        let status = detect_status(&text);
+        if text.contains("情報") {
+            if let Some((type_part, rest)) = text.split_once("情報") {
+
+                if let Some(date_location) = rest.split_once("頃、") {
+                    let date_time_str = date_location.0.trim();
+                    let location_info = date_location.1;
+
+                    if let Some(time_start) = date_time_str.rfind("日") {
+                        let time_part = &date_time_str[time_start + "日".len()..];
+                        let time = time_part
+                            .replace("時", ":")
+                            .replace("分", "")
+                            .trim()
+                            .to_string();
+
+                        // 「付近で」に続かない誤報の文面からも住所を取り出せるよう、「付近」で区切る
+                        if let Some((address_part, disaster_detail)) = location_info.split_once("付近") {
+                            let disaster_detail = disaster_detail.trim_start_matches(['で', 'の']);
+                            let address = if address_part.contains("　") {
+                                address_part
+                                    .split("　")
+                                    .next()
+                                    .map(|s| format!("千葉県{}", s.trim()))
+                                    .unwrap_or_else(|| format!("千葉県{}", address_part.trim()))
+                            } else {
+                                format!("千葉県{}", address_part.trim())
+                            };
+
+                            let disaster_type_detail = disaster_detail
+                                .split("が発生")
+                                .next()
+                                .unwrap_or("")
+                                .trim();
+
+                            // 誤報の場合は「火災情報」の「火災」などを災害種別とする
+                            let full_disaster_type = if status == IncidentStatus::FalseAlarm {
+                                type_part.trim().to_string()
+                            } else {
+                                disaster_type_detail.to_string()
+                            };
+
+                            disaster_data.push(json!({
+                                "type": full_disaster_type,
+                                "address": address,
+                                "time": time,
+                                "status": status.as_str(),
+                                "detail": extract_detail(disaster_detail, &full_disaster_type)
+                            }));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let source = json!([
+        {
+            "url": GET_SOURCE,
+            "name": "安房郡市広域市町村圏事務組合消防本部"
+        }
+    ]);
+
+    eprintln!("{:?}", disaster_data);
+    save_department_json(ctx, "awa", source, disaster_data)?;
+    println!("JSONファイルが出力されました: 122050.json, 122238.json, 122343.json, 124630.json （安房郡市広域市町村圏事務組合消防本部）");
+    Ok(())
+}
@@ -1,17 +1,18 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
+use crate::units::extract_detail;
 use scraper::{Html, Selector};
 use crate::to_half_width;
+use crate::status::{detect_status, strip_status_phrase, IncidentStatus};
 use chrono::Local;
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_department_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "www.city.hagi.lg.jp";
 const LIST_URL: &str = "https://www.city.hagi.lg.jp/soshiki/list8-1.html";
 
-pub fn return_352047() -> Result<(), Box<dyn std::error::Error>> {
-    println!("352047, 萩市（萩市消防本部）");
+/// 萩市消防本部の出動情報を取得し、管轄市町（萩市・阿武町）ごとに書き出す
+pub fn return_hagi(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    println!("352047, 355020, 萩市消防本部");
 
     // 現在の日付を取得（前日の計算用）
     let now = Local::now();
@@ -32,8 +33,9 @@ pub fn return_352047() -> Result<(), Box<dyn std::error::Error>> {
     for item_element in list_document.select(&item_selector) {
         let item_text = item_element.text().collect::<String>();
 
-        // 「発生」が含まれているもののみ処理（「鎮火」は除外）
-        if !item_text.contains("発生") || item_text.contains("鎮火") {
+        // 「鎮火」などから災害の状況を判定し、「発生」も状況を表す語も無いものは処理しない
+        let status = detect_status(&item_text);
+        if !item_text.contains("発生") && status == IncidentStatus::Dispatched {
             continue;
         }
 
@@ -89,24 +91,19 @@ pub fn return_352047() -> Result<(), Box<dyn std::error::Error>> {
                                 // 1行目: 住所（例: "萩市大字御許町"）
                                 let address_raw = lines[0];
 
-                                // 萩市の情報のみ処理
-                                if !address_raw.contains("萩市") {
-                                    continue;
-                                }
-
-                                let address = if address_raw.starts_with("萩市") {
-                                    format!("山口県{}", address_raw)
-                                } else {
+                                // 管轄市町への振り分けは`save_department_json`で行う
+                                let address = if address_raw.starts_with("山口県") {
                                     address_raw.to_string()
+                                } else {
+                                    format!("山口県{}", address_raw)
                                 };
 
-                                // 3行目: 災害種別（例: "建物火災が発生しました。"）
+                                // 3行目: 災害種別（例: "建物火災が発生しました。"、鎮火などの場合は状況を表す語の前まで）
                                 let disaster_type = if lines.len() >= 3 {
-                                    lines[2]
+                                    let text = lines[2]
                                         .replace("が発生しました。", "")
-                                        .replace("が発生しました", "")
-                                        .trim()
-                                        .to_string()
+                                        .replace("が発生しました", "");
+                                    strip_status_phrase(&text).to_string()
                                 } else {
                                     "不明".to_string()
                                 };
@@ -117,7 +114,9 @@ pub fn return_352047() -> Result<(), Box<dyn std::error::Error>> {
                                 disaster_data.push(json!({
                                     "type": disaster_type,
                                     "address": address,
-                                    "time": time
+                                    "time": time,
+                                    "status": status.as_str(),
+                                    "detail": extract_detail(lines.get(2).copied().unwrap_or(""), &disaster_type)
                                 }));
                             }
                         }
@@ -131,20 +130,16 @@ pub fn return_352047() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
-    let output = json!({
-        "jisx0402": "352047",
-        "source": [
-            {
-                "url": LIST_URL,
-                "name": "萩市消防本部"
-            }
-        ],
-        "disasters": disaster_data
-    });
-
-    let mut file = File::create("dist/352047.json")?;
-    file.write_all(output.to_string().as_bytes())?;
-    eprintln!("{:?}", output);
-    println!("JSONファイルが出力されました: 352047.json （萩市・萩市消防本部）");
+    let source = json!([
+        {
+            "url": LIST_URL,
+            "name": "萩市消防本部"
+        }
+    ]);
+
+    // 管轄市町ごとのJSONファイルに書き出し
+    eprintln!("{:?}", disaster_data);
+    save_department_json(ctx, "hagi", source, disaster_data)?;
+    println!("JSONファイルが出力されました: 352047.json, 355020.json （萩市消防本部）");
     Ok(())
 }
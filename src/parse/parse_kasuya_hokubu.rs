@@ -0,0 +1,87 @@
+use serde_json::json;
+
+use super::super::{get_source_with_config, save_department_json, to_half_width, HttpRequestConfig, RunContext};
+
+const HOST: &str = "m119.city.fukuoka.lg.jp";
+const GET_SOURCE: &str = "https://m119.city.fukuoka.lg.jp/kasuhoku/hpinfo.html";
+
+fn getsource() -> Result<String, Box<dyn std::error::Error>> {
+    let config = HttpRequestConfig::new(HOST, GET_SOURCE);
+    get_source_with_config(&config)
+}
+
+/// 粕屋北部消防本部の出動情報を取得し、構成市町村（古賀市・新宮町）ごとに書き出す
+pub fn return_kasuya_hokubu(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    println!("402231, 403458, 粕屋北部消防本部");
+    let body = getsource()?;
+    let document = scraper::Html::parse_document(&body);
+
+    let selector = scraper::Selector::parse("dl.emergencyinfo dd").unwrap();
+    let mut disaster_data = vec![];
+
+    for element in document.select(&selector) {
+        let text = element.text().collect::<String>().trim().to_string();
+
+        // 「災害が発生しておりません」という文字列があった場合は終了
+        if text.contains("災害が発生しておりません") {
+            break;
+        }
+
+        // 例: １６：１７　古賀市　小竹６１９番付近に警戒のため、消防隊が出動しています。
+
+        // 時刻を抽出（全角数字を半角に変換）
+        let time = to_half_width(
+            text
+                .split('　')
+                .next()
+                .unwrap_or("")
+                .trim()
+        ).replace('：', ":");
+
+        // 住所部分を抽出（時刻の後から「に」または「で」まで。構成市町への振り分けは`save_department_json`で行う）
+        let address_part = match text.split_once('　') {
+            Some((_, after_time)) => {
+                let end_pos = after_time.find('に').or_else(|| after_time.find('で')).unwrap_or(after_time.len());
+                format!("福岡県{}", &after_time[..end_pos])
+            }
+            None => String::new(),
+        };
+
+        // 災害種別を抽出（「に」または「で」の後、「のため」まで）
+        let disaster_type = if let Some(ni_pos) = text.find('に') {
+            text[ni_pos + 'に'.len_utf8()..]
+                .split("のため")
+                .next()
+                .unwrap_or("")
+                .trim()
+                .to_string()
+        } else if let Some(de_pos) = text.find('で') {
+            text[de_pos + 'で'.len_utf8()..]
+                .split("のため")
+                .next()
+                .unwrap_or("")
+                .trim()
+                .to_string()
+        } else {
+            "不明".to_string()
+        };
+
+        disaster_data.push(json!({
+            "type": disaster_type,
+            "address": address_part,
+            "time": time
+        }));
+    }
+
+    let source = json!([
+        {
+            "url": GET_SOURCE,
+            "name": "粕屋北部消防本部"
+        }
+    ]);
+
+    eprintln!("{:?}", disaster_data);
+    save_department_json(ctx, "kasuya-hokubu", source, disaster_data)?;
+    println!("JSONファイルが出力されました: 402231.json, 403458.json （粕屋北部消防本部）");
+    Ok(())
+}
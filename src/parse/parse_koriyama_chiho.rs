@@ -1,9 +1,8 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
 use crate::to_half_width;
+use crate::status::detect_status;
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_department_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "www.shobo.koriyama.fukushima.jp";
 const GET_SOURCE: &str = "https://www.shobo.koriyama.fukushima.jp/saigai/index.html";
@@ -13,8 +12,9 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_072036() -> Result<(), Box<dyn std::error::Error>> {
-    println!("072036, 郡山市（郡山地方広域消防組合）");
+/// 郡山地方広域消防組合の出動情報を取得し、構成市町村（郡山市・田村市・三春町・小野町）ごとに書き出す
+pub fn return_koriyama_chiho(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    println!("072036, 072117, 075213, 075221, 郡山地方広域消防組合");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
 
@@ -28,10 +28,8 @@ pub fn return_072036() -> Result<(), Box<dyn std::error::Error>> {
         // 全角数字を半角数字に変換
         let text = to_half_width(&text);
 
-        // 鎮火情報が含まれている場合はスキップ
-        if text.contains("鎮火") {
-            continue;
-        }
+        // 鎮火情報などから災害の状況を判定
+        let status = detect_status(&text);
 
         // <hr>で分割（前半: 時刻、後半: 災害種別と住所）
         if let Some((time_part, content_part)) = text.split_once("<hr>") {
@@ -61,47 +59,40 @@ pub fn return_072036() -> Result<(), Box<dyn std::error::Error>> {
                     .unwrap_or("")
                     .trim();
 
-                // 郡山市の情報のみ処理
-                if address_raw.contains("郡山市") {
-                    // 住所から「田村郡」とスペースを削除
-                    let address_cleaned = address_raw
-                        .replace("田村郡", "")
-                        .replace(" ", "")
-                        .replace("　", "");
+                // 住所から「田村郡」とスペースを削除
+                let address_cleaned = address_raw
+                    .replace("田村郡", "")
+                    .replace(" ", "")
+                    .replace("　", "");
 
-                    // 福島県を追加
-                    let address = if address_cleaned.starts_with("郡山市") {
-                        format!("福島県{}", address_cleaned)
-                    } else {
-                        address_cleaned.to_string()
-                    };
+                // 福島県を追加（構成市町村への振り分けは`save_department_json`で行う）
+                let address = if address_cleaned.is_empty() || address_cleaned.starts_with("福島県") {
+                    address_cleaned
+                } else {
+                    format!("福島県{}", address_cleaned)
+                };
 
-                    if !time.is_empty() && !disaster_type.is_empty() && !address.is_empty() {
-                        disaster_data.push(json!({
-                            "type": disaster_type,
-                            "address": address,
-                            "time": time
-                        }));
-                    }
+                if !time.is_empty() && !disaster_type.is_empty() && !address.is_empty() {
+                    disaster_data.push(json!({
+                        "type": disaster_type,
+                        "address": address,
+                        "time": time,
+                        "status": status.as_str()
+                    }));
                 }
             }
         }
     }
 
-    let output = json!({
-        "jisx0402": "072036",
-        "source": [
-            {
-                "url": GET_SOURCE,
-                "name": "郡山地方広域消防組合消防本部"
-            }
-        ],
-        "disasters": disaster_data
-    });
+    let source = json!([
+        {
+            "url": GET_SOURCE,
+            "name": "郡山地方広域消防組合消防本部"
+        }
+    ]);
 
-    let mut file = File::create("dist/072036.json")?;
-    file.write_all(output.to_string().as_bytes())?;
-    eprintln!("{:?}", output);
-    println!("JSONファイルが出力されました: 072036.json （郡山市・郡山地方広域消防組合）");
+    eprintln!("{:?}", disaster_data);
+    save_department_json(ctx, "koriyama-chiho", source, disaster_data)?;
+    println!("JSONファイルが出力されました: 072036.json, 072117.json, 075213.json, 075221.json （郡山地方広域消防組合）");
     Ok(())
 }
@@ -0,0 +1,79 @@
+use serde_json::json;
+
+use super::super::{get_source_with_config, save_department_json, HttpRequestConfig, RunContext};
+
+const HOST: &str = "www.om119.jp";
+const GET_SOURCE: &str = "https://www.om119.jp/section/saigaiPc.html";
+
+fn getsource() -> Result<String, Box<dyn std::error::Error>> {
+    let config = HttpRequestConfig::new(HOST, GET_SOURCE).with_shift_jis(true);
+    get_source_with_config(&config)
+}
+
+/// 大阪南消防組合の出動情報を取得し、構成市町村（富田林市・河内長野市・柏原市・羽曳野市・藤井寺市・太子町・河南町・千早赤阪村）ごとに書き出す
+pub fn return_osaka_minami(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    println!("272141, 272167, 272213, 272221, 272264, 273813, 273821, 273830, 大阪南消防組合");
+    let body = getsource()?;
+    let document = scraper::Html::parse_document(&body);
+    let selector = scraper::Selector::parse("ul").unwrap();
+    let mut disaster_data = vec![];
+
+    // 最初の<ul>要素のみを取得（現在発生している災害）
+    if let Some(ul_element) = document.select(&selector).next() {
+        let li_selector = scraper::Selector::parse("li span.item").unwrap();
+
+        // 各<li>要素を解析
+        for element in ul_element.select(&li_selector) {
+            let text = element.text().collect::<String>().trim().to_string();
+
+            // テキストを解析: "11月6日14時49分ごろ、富田林市津々山台１丁目付近において、救急車の応援の通報により出動中です。"
+            let parts: Vec<&str> = text.split("、").collect();
+            if parts.len() >= 3 {
+                // 時刻部分を抽出
+                let time_part = parts[0];
+                let time = time_part
+                    .split("日")
+                    .nth(1)
+                    .unwrap_or("")
+                    .replace("時", ":")
+                    .replace("分ごろ", "");
+
+                // 住所部分を抽出
+                let address_part = parts[1];
+                let address = address_part
+                    .replace("付近において", "")
+                    .trim()
+                    .to_string();
+                // 構成市町村への振り分けは`save_department_json`で行う
+                let full_address = format!("大阪府{}", address);
+
+                // 種別部分を抽出
+                let type_part = parts[2];
+                let disaster_type = type_part
+                    .replace("の通報により出動中です。", "")
+                    .replace("の通報により出動しました。", "")
+                    .trim()
+                    .to_string();
+
+                disaster_data.push(json!({
+                    "type": disaster_type,
+                    "address": full_address,
+                    "time": time
+                }));
+            }
+        }
+    }
+
+    let source = json!([
+        {
+            "url": GET_SOURCE,
+            "name": "大阪南消防組合"
+        }
+    ]);
+
+    // 構成市町村ごとのJSONファイルに書き出し
+    eprintln!("{:?}", disaster_data);
+    save_department_json(ctx, "osaka-minami", source, disaster_data)?;
+    println!("JSONファイルが出力されました: 272141.json, 272167.json, 272213.json, 272221.json, 272264.json, 273813.json, 273821.json, 273830.json （大阪南消防組合）");
+    Ok(())
+}
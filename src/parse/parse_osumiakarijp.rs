@@ -1,4 +1,4 @@
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, HttpRequestConfig, RunContext};
 
 const HOST: &str = "www.osumiakari.jp";
 const GET_SOURCE: &str = "https://www.osumiakari.jp/about/index.html";
@@ -11,7 +11,7 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
 }
 
 // 結果を返却
-pub fn return_999999() -> Result<(), Box<dyn std::error::Error>> {
+pub fn return_999999(_ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
     println!("999999, テスト用");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
@@ -1,8 +1,8 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
+use crate::jisx0402;
+use crate::units::extract_detail;
 
-use super::super::{get_source_with_config, to_half_width, HttpRequestConfig};
+use super::super::{get_source_with_config, save_department_json, to_half_width, HttpRequestConfig, RunContext};
 
 const HOST: &str = "www.chubu.saga.saga.jp";
 const GET_SOURCE: &str = "https://www.chubu.saga.saga.jp/disaster/index.html";
@@ -12,8 +12,9 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_412015() -> Result<(), Box<dyn std::error::Error>> {
-    println!("412015, 佐賀市(佐賀広域消防局)");
+/// 佐賀広域消防局の出動情報を取得し、構成市町村（佐賀市・多久市・小城市・神埼市・吉野ヶ里町）ごとに書き出す
+pub fn return_saga_koiki(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    println!("412015, 412040, 412082, 412104, 413275, 佐賀広域消防局");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
     let selector = scraper::Selector::parse("table.SGINFO tr").unwrap();
@@ -35,16 +36,8 @@ pub fn return_412015() -> Result<(), Box<dyn std::error::Error>> {
 
             let text = cells[1].clone();
 
-            // 他の市町村名が含まれている場合はスキップ（逆フィルタリング）
-            if text.contains("多久市")
-                || text.contains("小城市")
-                || text.contains("神埼市")
-                || text.contains("吉野ヶ里町")
-            {
-                continue;
-            }
-
             // フォーマット: "１０：０３ごろ神野東２丁目でＰＡ連携が発生しています。"
+            //             "１０：０３ごろ多久市東多久町別府でPA連携が発生しています。"（佐賀市以外は市町名から始まる）
             if let Some((time_part, rest)) = text.split_once("ごろ") {
                 if let Some((address_part, disaster_info)) = rest.split_once("で") {
                     // 時刻を抽出（全角数字と全角コロンを半角に変換）
@@ -53,9 +46,15 @@ pub fn return_412015() -> Result<(), Box<dyn std::error::Error>> {
                         .trim()
                         .to_string();
 
-                    // 住所を抽出（佐賀市名を追加）
+                    // 住所を抽出（市町名が無い場合は佐賀市を追加。構成市町への振り分けは`save_department_json`で行う）
                     let address = address_part.trim();
-                    let full_address = format!("佐賀県佐賀市{}", address);
+                    let full_address = if jisx0402::match_municipality(Some("佐賀県"), address).is_some()
+                        || address.starts_with("神埼郡")
+                    {
+                        format!("佐賀県{}", address)
+                    } else {
+                        format!("佐賀県佐賀市{}", address)
+                    };
 
                     // 災害種別を抽出
                     let disaster_type = disaster_info
@@ -72,28 +71,24 @@ pub fn return_412015() -> Result<(), Box<dyn std::error::Error>> {
                     disaster_data.push(json!({
                         "type": disaster_type,
                         "address": full_address,
-                        "time": time
+                        "time": time,
+                        "detail": extract_detail(disaster_info, &disaster_type)
                     }));
                 }
             }
         }
     }
 
-    let output = json!({
-        "jisx0402": "412015",
-        "source": [
-            {
-                "url": GET_SOURCE,
-                "name": "佐賀広域消防局"
-            }
-        ],
-        "disasters": disaster_data
-    });
+    let source = json!([
+        {
+            "url": GET_SOURCE,
+            "name": "佐賀広域消防局"
+        }
+    ]);
 
-    // JSONファイルに書き出し
-    let mut file = File::create("dist/412015.json")?;
-    file.write_all(output.to_string().as_bytes())?;
-    eprintln!("{:?}", output);
-    println!("JSONファイルが出力されました: 412015.json （佐賀市・佐賀広域消防局）");
+    // 構成市町村ごとのJSONファイルに書き出し
+    eprintln!("{:?}", disaster_data);
+    save_department_json(ctx, "saga-koiki", source, disaster_data)?;
+    println!("JSONファイルが出力されました: 412015.json, 412040.json, 412082.json, 412104.json, 413275.json （佐賀広域消防局）");
     Ok(())
 }
@@ -0,0 +1,96 @@
+use serde_json::json;
+use crate::to_half_width;
+use crate::status::{detect_status, strip_status_phrase};
+use crate::units::extract_detail;
+
+use super::super::{get_source_with_config, save_department_json, HttpRequestConfig, RunContext};
+
+const HOST: &str = "www.seibu-saitama119.jp";
+const GET_SOURCE: &str = "http://www.seibu-saitama119.jp/disaster/sakatsuru/saigai/pc/";
+
+fn getsource() -> Result<String, Box<dyn std::error::Error>> {
+    let config = HttpRequestConfig::new(HOST, GET_SOURCE).with_shift_jis(true);
+    get_source_with_config(&config)
+}
+
+/// 坂戸・鶴ヶ島消防本部の出動情報を取得し、構成市町村（坂戸市・鶴ヶ島市）ごとに書き出す
+pub fn return_sakado_tsurugashima(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    println!("112399, 112411, 坂戸・鶴ヶ島消防本部");
+    let body = getsource()?;
+    let document = scraper::Html::parse_document(&body);
+
+    // 災害情報を取得
+    let selector = scraper::Selector::parse("html body div table.SGINFO tbody tr td").unwrap();
+    let mut disaster_data = vec![];
+
+    for element in document.select(&selector) {
+        // 全角数字を半角数字に変換
+        let text = to_half_width(&element.text().collect::<String>())
+            .replace('　', "")
+            .replace(' ', "")
+            .trim()
+            .to_string();
+
+        // 「発生しておりません」が含まれている場合は処理を中断
+        if text.contains("発生しておりません") {
+            continue;
+        }
+
+        // 「終了」や「鎮火しました」などから災害の状況を判定
+        let status = detect_status(&text);
+
+        // 時刻を抽出（「月日時分頃」から「時分」を抽出）
+        if let Some(time_part) = text.split("頃、").next() {
+            // 最後の6文字（「22時33分」の形式）を取得
+            let time = time_part
+                .chars()
+                .rev()
+                .take(6)
+                .collect::<String>()
+                .chars()
+                .rev()
+                .collect::<String>()
+                .replace("時", ":")
+                .replace("分", "");
+
+            // 住所を抽出（「頃、」以降、「で」まで。構成市町村への振り分けは`save_department_json`で行う）
+            if let Some((_, after_time)) = text.split_once("頃、") {
+                if let Some((location_part, disaster_part)) = after_time.split_once("で") {
+                    // 住所を整形（「地内」を除去）
+                    let address = format!("埼玉県{}", location_part.replace("地内", "").trim());
+
+                    // 災害種別を抽出（「で」以降、「が発生」まで。鎮火などの場合は状況を表す語の前まで）
+                    let disaster_type = strip_status_phrase(
+                        disaster_part
+                            .split("が発生")
+                            .next()
+                            .unwrap_or("")
+                    )
+                    .to_string();
+
+                    if !disaster_type.is_empty() && !address.is_empty() && !time.is_empty() {
+                        disaster_data.push(json!({
+                            "type": disaster_type,
+                            "address": address,
+                            "time": time,
+                            "status": status.as_str(),
+                            "detail": extract_detail(disaster_part, &disaster_type)
+                        }));
+                    }
+                }
+            }
+        }
+    }
+
+    let source = json!([
+        {
+            "url": GET_SOURCE,
+            "name": "坂戸・鶴ヶ島消防本部"
+        }
+    ]);
+
+    eprintln!("{:?}", disaster_data);
+    save_department_json(ctx, "sakado-tsurugashima", source, disaster_data)?;
+    println!("JSONファイルが出力されました: 112399.json, 112411.json （坂戸・鶴ヶ島消防本部）");
+    Ok(())
+}
@@ -1,8 +1,6 @@
 use serde_json::json;
-use std::fs::File;
-use std::io::Write;
 
-use super::super::{get_source_with_config, HttpRequestConfig};
+use super::super::{get_source_with_config, save_department_json, HttpRequestConfig, RunContext};
 
 const HOST: &str = "www.sakatashobo.jp";
 const GET_SOURCE: &str = "http://www.sakatashobo.jp/";
@@ -12,8 +10,9 @@ fn getsource() -> Result<String, Box<dyn std::error::Error>> {
     get_source_with_config(&config)
 }
 
-pub fn return_062049() -> Result<(), Box<dyn std::error::Error>> {
-    println!("062049, 酒田市（酒田地区広域行政組合消防本部）");
+/// 酒田地区広域行政組合の出動情報を取得し、構成市町（酒田市・庄内町・遊佐町）ごとに書き出す
+pub fn return_sakata_chiku(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    println!("062049, 064289, 064611, 酒田地区広域行政組合消防本部");
     let body = getsource()?;
     let document = scraper::Html::parse_document(&body);
 
@@ -47,8 +46,8 @@ pub fn return_062049() -> Result<(), Box<dyn std::error::Error>> {
             let disaster_category = &cells[2]; // "防災ヘリ支援"
             let address = &cells[4]; // "庄内町立谷沢字玉川"
 
-            // 酒田市の災害のみをフィルタリング
-            if address.starts_with("酒田市") {
+            // 構成市町への振り分けは`save_department_json`で行う
+            if !address.is_empty() {
                 // 時刻を HH:MM 形式に変換
                 let time = time_str
                     .split_whitespace()
@@ -74,21 +73,16 @@ pub fn return_062049() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
-    let output = json!({
-        "jisx0402": "062049",
-        "source": [
-            {
-                "url": GET_SOURCE,
-                "name": "酒田地区広域行政組合消防本部"
-            }
-        ],
-        "disasters": disaster_data
-    });
-
-    // JSONファイルに書き出し
-    let mut file = File::create("dist/062049.json")?;
-    file.write_all(output.to_string().as_bytes())?;
-    eprintln!("{:?}", output);
-    println!("JSONファイルが出力されました: 062049.json （酒田市消防本部（酒田地区広域行政組合消防本部））");
+    let source = json!([
+        {
+            "url": GET_SOURCE,
+            "name": "酒田地区広域行政組合消防本部"
+        }
+    ]);
+
+    // 構成市町ごとのJSONファイルに書き出し
+    eprintln!("{:?}", disaster_data);
+    save_department_json(ctx, "sakata-chiku", source, disaster_data)?;
+    println!("JSONファイルが出力されました: 062049.json, 064289.json, 064611.json （酒田地区広域行政組合消防本部）");
     Ok(())
 }
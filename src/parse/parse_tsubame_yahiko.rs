@@ -0,0 +1,126 @@
+use crate::status::{detect_status, strip_status_phrase};
+use crate::to_half_width;
+use crate::units::extract_detail;
+use serde_json::json;
+
+use super::super::{HttpRequestConfig, RunContext, get_source_with_config, save_department_json};
+
+const HOST: &str = "www.tysogo.jp";
+const GET_SOURCE: &str = "https://www.tysogo.jp/status.html";
+
+// 弥彦村の大字リスト
+const YAHIKO_OAZA: &[&str] = &[
+    "麓",
+    "村山",
+    "観音寺",
+    "弥彦",
+    "走出",
+    "上泉",
+    "井田",
+    "山岸",
+    "山崎",
+    "中山",
+    "矢作",
+    "平野",
+    "えび穴",
+    "魵穴",
+    "大戸",
+    "美山",
+    "峰見",
+];
+
+fn getsource() -> Result<String, Box<dyn std::error::Error>> {
+    let config = HttpRequestConfig::new(HOST, GET_SOURCE).with_shift_jis(true);
+    get_source_with_config(&config)
+}
+
+fn is_yahiko_location(location: &str) -> bool {
+    YAHIKO_OAZA.iter().any(|&oaza| location.contains(oaza))
+}
+
+/// 燕・弥彦総合事務組合消防本部の出動情報を取得し、構成市町村（燕市・弥彦村）ごとに書き出す
+pub fn return_tsubame_yahiko(ctx: &RunContext) -> Result<(), Box<dyn std::error::Error>> {
+    println!("152137, 153427, 燕・弥彦総合事務組合消防本部");
+    let body = getsource()?;
+    let document = scraper::Html::parse_document(&body);
+
+    // 災害情報を取得
+    let selector = scraper::Selector::parse("ul#FDstatus li").unwrap();
+    let mut disaster_data = vec![];
+
+    for element in document.select(&selector) {
+        // 全角数字を半角数字に変換
+        let text = to_half_width(&element.text().collect::<String>())
+            .replace('　', "")
+            .replace(' ', "")
+            .trim()
+            .to_string();
+
+        // 「（終了）」や「鎮火」などから災害の状況を判定
+        let status = detect_status(&text);
+
+        // フォーマット: "01月14日12時32分頃に吉田本所地内で救急支援のため消防車が出動しました。"
+        if let Some(time_location_part) = text.split("頃に").nth(1) {
+            // 時刻を抽出
+            if let Some(time_part) = text.split("頃に").next() {
+                // 最後の6文字（「12時32分」の形式）を取得
+                let time = time_part
+                    .chars()
+                    .rev()
+                    .take(6)
+                    .collect::<String>()
+                    .chars()
+                    .rev()
+                    .collect::<String>()
+                    .replace("時", ":")
+                    .replace("分", "");
+
+                // 場所と災害種別を抽出
+                if let Some((location_part, disaster_part)) =
+                    time_location_part.split_once("地内で")
+                {
+                    let location = location_part.trim();
+
+                    // 災害種別を抽出（「地内で」以降、「のため消防車が出動しました」まで）
+                    let disaster_type = disaster_part
+                        .split("のため消防車が出動しました")
+                        .next()
+                        .unwrap_or("")
+                        .replace("。", "");
+                    let disaster_type = strip_status_phrase(&disaster_type).to_string();
+
+                    // ソースに市村名が無いため、弥彦村の大字であれば弥彦村、それ以外は燕市の住所とする
+                    let address = if is_yahiko_location(location) {
+                        format!("新潟県西蒲原郡弥彦村{}", location)
+                    } else {
+                        format!("新潟県燕市{}", location)
+                    };
+
+                    if !disaster_type.is_empty() && !address.is_empty() && !time.is_empty() {
+                        disaster_data.push(json!({
+                            "type": disaster_type,
+                            "address": address,
+                            "time": time,
+                            "status": status.as_str(),
+                            "detail": extract_detail(disaster_part, &disaster_type)
+                        }));
+                    }
+                }
+            }
+        }
+    }
+
+    let source = json!([
+        {
+            "url": GET_SOURCE,
+            "name": "燕・弥彦総合事務組合消防本部"
+        }
+    ]);
+
+    eprintln!("{:?}", disaster_data);
+    save_department_json(ctx, "tsubame-yahiko", source, disaster_data)?;
+    println!(
+        "JSONファイルが出力されました: 152137.json, 153427.json （燕・弥彦総合事務組合消防本部）"
+    );
+    Ok(())
+}
@@ -0,0 +1,415 @@
+use jsonschema::Validator;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use regex::Regex;
+use serde_json::{json, Value};
+
+use crate::category::{Category, Subcategory};
+use crate::envelope;
+use crate::geocode::Precision;
+use crate::health::{self, SourceStatus};
+use crate::location::Location;
+use crate::context::RunContext;
+use crate::department;
+use crate::status::IncidentStatus;
+
+/// JSON Schemaの仕様の版
+pub const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// 出力するJSON Schemaを保存するディレクトリ（出力先のディレクトリからの相対位置）
+pub const SCHEMA_DIR: &str = "schema";
+
+/// 出動時刻の形式（`HH:MM`）
+pub const TIME_PATTERN: &str = "^([01]?[0-9]|2[0-3]):[0-5][0-9]$";
+
+/// 地方公共団体コードの形式
+const JISX0402_PATTERN: &str = "^[0-9]{6}$";
+
+/// 空白以外の文字を含む文字列
+const NOT_BLANK_PATTERN: &str = "\\S";
+
+/// 識別子の一覧と`null`を許容する列挙
+fn nullable_enum<'a>(values: impl IntoIterator<Item = &'a str>) -> Value {
+    let mut values: Vec<Value> = values.into_iter().map(|value| json!(value)).collect();
+    values.push(Value::Null);
+    json!({ "enum": values })
+}
+
+/// 文字列または`null`
+fn nullable_string() -> Value {
+    json!({ "type": ["string", "null"] })
+}
+
+/// ソースとなる機関
+fn source_schema() -> Value {
+    json!({
+        "type": "array",
+        "items": {
+            "type": "object",
+            "required": ["name", "url"],
+            "properties": {
+                "name": { "type": "string", "pattern": NOT_BLANK_PATTERN },
+                "url": { "type": "string", "pattern": "^https?://" }
+            }
+        }
+    })
+}
+
+/// 出動情報1件（`save_disaster_json`で正規化した後のもの）
+pub fn disaster_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["id", "time", "type", "address", "category", "status"],
+        "properties": {
+            "id": { "type": "string", "minLength": 1 },
+            "time": { "type": "string", "pattern": TIME_PATTERN },
+            "type": { "type": "string", "pattern": NOT_BLANK_PATTERN },
+            "address": { "type": "string", "pattern": NOT_BLANK_PATTERN },
+            "detail": { "type": "string" },
+            "category": { "enum": Category::ALL.map(|c| c.as_str()) },
+            "subcategory": nullable_enum(Subcategory::ALL.map(|s| s.as_str())),
+            "status": { "enum": IncidentStatus::ALL.map(|s| s.as_str()) },
+            "units": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["kind", "count"],
+                    "properties": {
+                        "kind": { "type": "string", "minLength": 1 },
+                        "count": { "type": ["integer", "null"], "minimum": 1 }
+                    }
+                }
+            },
+            "prefecture": nullable_string(),
+            "municipality": nullable_string(),
+            "ward": nullable_string(),
+            "town": nullable_string(),
+            "chome": { "type": ["integer", "null"], "minimum": 1 },
+            "remainder": nullable_string(),
+            "location": {
+                "type": "object",
+                "required": ["kind"],
+                "properties": { "kind": { "enum": Location::KINDS } }
+            },
+            "lat": { "type": ["number", "null"], "minimum": -90, "maximum": 90 },
+            "lon": { "type": ["number", "null"], "minimum": -180, "maximum": 180 },
+            "precision": nullable_enum(Precision::ALL.map(|p| p.as_str()))
+        }
+    })
+}
+
+/// 市区町村ごとのファイルのデータ本体（`all.json`の各要素と共通の部分）
+fn municipality_data_schema(with_code: bool) -> Value {
+    let mut required = vec!["source", "disasters", "status"];
+    if with_code {
+        required.insert(0, "jisx0402");
+    }
+    let mut schema = json!({
+        "type": "object",
+        "required": required,
+        "properties": {
+            "department": nullable_string(),
+            "prefecture": nullable_string(),
+            "name": nullable_string(),
+            "name_kana": nullable_string(),
+            "source": source_schema(),
+            "disasters": { "type": "array", "items": disaster_schema() },
+            "fetched_at": { "type": ["string", "null"], "format": "date-time" },
+            "status": { "enum": SourceStatus::ALL.map(|s| s.as_str()) }
+        }
+    });
+    if with_code {
+        schema["properties"]["jisx0402"] = json!({ "type": "string", "pattern": JISX0402_PATTERN });
+    }
+    schema
+}
+
+/// 市区町村ごとのファイル（`（地方公共団体コード）.json`）
+pub fn municipality_schema() -> Value {
+    envelope::schema(municipality_data_schema(true))
+}
+
+/// 全量JSON（`all.json`）
+pub fn all_schema() -> Value {
+    envelope::schema(json!({
+        "type": "object",
+        "propertyNames": { "pattern": JISX0402_PATTERN },
+        "additionalProperties": municipality_data_schema(false)
+    }))
+}
+
+/// 対応している地方公共団体コードの一覧（`list.json`）
+///
+/// 第1版の形式では地方公共団体コードの文字列の配列となる。
+pub fn list_schema() -> Value {
+    if envelope::is_compat_mode() {
+        return json!({
+            "type": "array",
+            "items": { "type": "string", "pattern": JISX0402_PATTERN }
+        });
+    }
+    envelope::schema(json!({
+        "type": "array",
+        "items": {
+            "type": "object",
+            "required": ["jisx0402"],
+            "properties": {
+                "jisx0402": { "type": "string", "pattern": JISX0402_PATTERN },
+                "department": nullable_string(),
+                "prefecture": nullable_string(),
+                "name": nullable_string(),
+                "name_kana": nullable_string()
+            }
+        }
+    }))
+}
+
+/// ソースごとの取得状況（`status.json`）
+pub fn status_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["generated_at", "source_count", "failed_count", "sources"],
+        "properties": {
+            "generated_at": { "type": "string", "format": "date-time" },
+            "source_count": { "type": "integer", "minimum": 0 },
+            "failed_count": { "type": "integer", "minimum": 0 },
+            "sources": {
+                "type": "object",
+                "propertyNames": { "pattern": JISX0402_PATTERN },
+                "additionalProperties": {
+                    "type": "object",
+                    "required": ["ok", "parser", "last_attempt", "consecutive_failures"],
+                    "properties": {
+                        "ok": { "type": "boolean" },
+                        "parser": { "type": "string", "minLength": 1 },
+                        "department": nullable_string(),
+                        "last_attempt": { "type": "string", "format": "date-time" },
+                        "last_success": { "type": ["string", "null"], "format": "date-time" },
+                        "http_status": { "type": ["integer", "null"] },
+                        "duration_ms": { "type": "integer", "minimum": 0 },
+                        "incident_count": { "type": ["integer", "null"], "minimum": 0 },
+                        "error_kind": nullable_enum(health::ERROR_KINDS),
+                        "error_message": nullable_string(),
+                        "consecutive_failures": { "type": "integer", "minimum": 0 }
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// 出力するJSON Schemaの一覧（名前とJSON Schemaの組）
+///
+/// 名前は`（名前）.schema.json`としてJSON Schemaを保存する際のファイル名となる。
+pub fn documents() -> Vec<(&'static str, Value)> {
+    [
+        ("municipality", "市区町村ごとの出動情報", municipality_schema()),
+        ("all", "全量JSON", all_schema()),
+        ("list", "対応している地方公共団体コードの一覧", list_schema()),
+        ("status", "ソースごとの取得状況", status_schema()),
+    ]
+    .into_iter()
+    .map(|(name, title, schema)| {
+        let mut document = json!({
+            "$schema": DIALECT,
+            "$id": format!("{}/{}.schema.json", SCHEMA_DIR, name),
+            "title": title
+        });
+        document.as_object_mut().unwrap().extend(schema.as_object().cloned().unwrap_or_default());
+        (name, document)
+    })
+    .collect()
+}
+
+/// 市区町村ごとのファイルがJSON Schemaに合わない場合のエラー
+#[derive(Debug)]
+pub struct SchemaError {
+    /// 地方公共団体コード
+    pub jisx0402: String,
+    /// JSON Schemaに合わない箇所
+    pub problems: Vec<String>,
+}
+
+impl std::fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.jsonがJSON Schemaに合いません（{}件）", self.jisx0402, self.problems.len())
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+/// 書き出す前の市区町村ごとのファイルをJSON Schemaで検証する関数
+///
+/// `save_disaster_json`が書き出す前に使用し、合わない場合はそのソースのみを失敗とする。
+pub fn validate_municipality(jisx0402: &str, output: &Value) -> Result<(), Box<dyn std::error::Error>> {
+    let document = documents()
+        .into_iter()
+        .find(|(name, _)| *name == "municipality")
+        .map(|(_, document)| document)
+        .ok_or("市区町村ごとのファイルのJSON Schemaがありません")?;
+    let validator = jsonschema::options().should_validate_formats(true).build(&document)?;
+    let problems: Vec<String> = validator
+        .iter_errors(output)
+        .map(|error| format!("{}: {}", error.instance_path, error))
+        .collect();
+    if problems.is_empty() {
+        return Ok(());
+    }
+    Err(SchemaError { jisx0402: jisx0402.to_string(), problems }.into())
+}
+
+/// 出力先のファイルを検証する関数
+///
+/// 市区町村ごとのファイル（対応している地方公共団体コードのもの）・`all.json`・`list.json`・`status.json`はJSON Schemaで、
+/// その他のJSON・GeoJSON・XMLファイルは構文のみを検証する。見つかった問題を`（ファイル）: （内容）`の形式で返す。
+pub fn validate_output(ctx: &RunContext) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let mut validators = vec![];
+    for (name, document) in documents() {
+        let validator = jsonschema::options().should_validate_formats(true).build(&document)?;
+        validators.push((name, validator));
+    }
+    let validator = |name: &str| validators.iter().find(|(n, _)| *n == name).map(|(_, v)| v);
+    let municipality_file = Regex::new(r"^(\d{6})\.json$")?;
+
+    let mut problems = vec![];
+    for path in ctx.list()? {
+        // 対応していない地方公共団体コードのファイルは、`get_all_json`と同様に出力に含めないため検証しない
+        if let Some(captures) = municipality_file.captures(&path)
+            && !department::is_supported(&captures[1])
+        {
+            continue;
+        }
+        let extension = path.rsplit_once('.').map(|(_, extension)| extension).unwrap_or_default();
+        let contents = ctx.read(&path)?.unwrap_or_default();
+        let result = match extension {
+            "json" | "geojson" => {
+                let schema = if municipality_file.is_match(&path) {
+                    validator("municipality")
+                } else {
+                    match path.as_str() {
+                        "all.json" => validator("all"),
+                        "list.json" => validator("list"),
+                        "status.json" => validator("status"),
+                        _ => None,
+                    }
+                };
+                validate_json(&contents, schema)
+            }
+            "xml" | "atom" => validate_xml(&contents),
+            _ => Ok(vec![]),
+        };
+        let location = ctx.location(&path);
+        match result {
+            Ok(errors) => problems.extend(errors.into_iter().map(|e| format!("{}: {}", location, e))),
+            Err(e) => problems.push(format!("{}: {}", location, e)),
+        }
+    }
+    Ok(problems)
+}
+
+/// JSONを解釈し、JSON Schemaがあれば検証する（JSON Schemaに合わない箇所を返す）
+fn validate_json(contents: &[u8], validator: Option<&Validator>) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let instance: Value = serde_json::from_slice(contents)?;
+    let Some(validator) = validator else {
+        return Ok(vec![]);
+    };
+    Ok(validator
+        .iter_errors(&instance)
+        .map(|error| format!("{}: {}", error.instance_path, error))
+        .collect())
+}
+
+/// XMLを最後まで読み込み、整形式であることを確認する（途中で途切れたものも問題とする）
+fn validate_xml(contents: &[u8]) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let mut reader = Reader::from_reader(contents);
+    let mut buf = vec![];
+    let mut depth = 0usize;
+    let mut has_root = false;
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(_) => {
+                depth += 1;
+                has_root = true;
+            }
+            Event::End(_) => depth = depth.saturating_sub(1),
+            Event::Empty(_) => has_root = true,
+            Event::Eof if !has_root => return Ok(vec!["ルート要素がありません".to_string()]),
+            Event::Eof if depth > 0 => return Ok(vec!["閉じられていない要素があります".to_string()]),
+            Event::Eof => return Ok(vec![]),
+            _ => {}
+        }
+        buf.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::MemorySink;
+
+    fn sapporo(time: &str) -> Value {
+        json!({
+            "jisx0402": "011002",
+            "source": [{ "url": "https://example.jp/", "name": "札幌市消防局" }],
+            "disasters": [{ "type": "建物火災", "address": "北海道札幌市中央区北1条西2丁目", "time": time }]
+        })
+    }
+
+    #[test]
+    fn saved_files_match_the_schema() {
+        let ctx = RunContext::new(Box::new(MemorySink::new()));
+        crate::save_disaster_json(&ctx, &sapporo("10:00")).unwrap();
+        let municipalities = crate::collect_municipality_data(&ctx).unwrap();
+        crate::generate_all_json(&ctx, &municipalities).unwrap();
+
+        assert_eq!(validate_output(&ctx).unwrap(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn rejects_a_source_that_does_not_match_the_schema() {
+        let sink = MemorySink::new();
+        let ctx = RunContext::new(Box::new(sink.clone()));
+        let error = crate::save_disaster_json(&ctx, &sapporo("")).unwrap_err();
+
+        assert!(error.is::<SchemaError>());
+        assert_eq!(health::error_kind(error.as_ref()), "schema");
+        assert!(sink.files().is_empty());
+        assert_eq!(ctx.state().len(), 0);
+    }
+
+    #[test]
+    fn fails_only_the_member_that_does_not_match_the_schema() {
+        let sink = MemorySink::new();
+        let ctx = RunContext::new(Box::new(sink.clone()));
+        let start = ctx.health().begin("return_tsubame_yahiko");
+        let result = crate::save_department_json(
+            &ctx,
+            "tsubame-yahiko",
+            json!([{ "url": "https://example.jp/", "name": "燕・弥彦総合事務組合消防本部" }]),
+            vec![
+                json!({ "type": "建物火災", "address": "新潟県燕市吉田本所", "time": "10:00" }),
+                json!({ "type": "救急支援", "address": "新潟県西蒲原郡弥彦村弥彦", "time": "" }),
+            ],
+        );
+        ctx.health().finish("return_tsubame_yahiko", start, &result);
+
+        assert!(result.is_ok());
+        assert_eq!(sink.files().keys().collect::<Vec<_>>(), vec!["152137.json"]);
+        assert_eq!(ctx.health().failed_codes(), vec!["153427"]);
+        let sources = health::source_status(ctx.health().attempts(), &Value::Null, |_| Some(1));
+        assert_eq!(sources["152137"]["ok"], json!(true));
+        assert_eq!(sources["153427"]["error_kind"], json!("schema"));
+    }
+
+    #[test]
+    fn ignores_files_of_unsupported_codes() {
+        let sink = MemorySink::new();
+        let ctx = RunContext::new(Box::new(sink.clone()));
+        // 地方公共団体コードの訂正前に、第1版の形式で書き出されたファイル
+        ctx.write("123013.json", json!({ "disasters": [{ "time": "10:00" }] }).to_string()).unwrap();
+        crate::save_disaster_json(&ctx, &sapporo("10:00")).unwrap();
+
+        assert_eq!(crate::get_all_json(&ctx).unwrap(), vec!["011002.json"]);
+        assert_eq!(validate_output(&ctx).unwrap(), Vec::<String>::new());
+    }
+}
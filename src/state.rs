@@ -0,0 +1,572 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, SecondsFormat, Timelike};
+use serde_json::{json, Map, Value};
+
+use crate::address::normalize_address;
+use crate::feed::incident_datetime;
+use crate::output;
+use crate::status::IncidentStatus;
+
+/// 出動情報の状態ファイルの既定の配置場所
+pub const DEFAULT_STATE_PATH: &str = "state/incidents.json";
+
+/// 出動情報の状態ファイルの場所を指定する環境変数
+pub const STATE_PATH_ENV: &str = "EMERGENCY_DISPATCH_STATE";
+
+/// 状態ファイルの場所（環境変数`EMERGENCY_DISPATCH_STATE`、無ければ`state/incidents.json`）
+pub fn state_path() -> PathBuf {
+    PathBuf::from(std::env::var(STATE_PATH_ENV).unwrap_or_else(|_| DEFAULT_STATE_PATH.to_string()))
+}
+
+/// 最後に観測してからこの日数を過ぎた出動情報は状態ファイルから削除する
+pub const RETENTION_DAYS: i64 = 7;
+
+/// 状態ファイルの形式の版
+const STATE_VERSION: u64 = 2;
+
+/// 観測のたびに変化を確認する出動情報のフィールド（指紋に含まれるフィールド以外で、掲載中に変わり得るもの）
+pub const TRACKED_FIELDS: &[&str] = &["status", "detail", "units"];
+
+/// 出動情報1件の状態
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncidentRecord {
+    /// 実行をまたいで変わらない出動情報の識別子（例: `202610191234-011002`）
+    pub id: String,
+    /// 同じ出動情報かを判定するための値（地方公共団体コード・出動日時・住所・種別から求める）
+    pub fingerprint: String,
+    pub jisx0402: String,
+    /// 出動日時（時刻を解釈できない場合は`None`）
+    pub time: Option<DateTime<FixedOffset>>,
+    pub disaster_type: String,
+    pub address: String,
+    /// `TRACKED_FIELDS`の各フィールドの最後に観測した値
+    pub fields: Map<String, Value>,
+    /// 初めて観測した日時
+    pub first_seen: DateTime<FixedOffset>,
+    /// 最後に観測した日時
+    pub last_seen: DateTime<FixedOffset>,
+}
+
+impl IncidentRecord {
+    /// 最後に観測した出動状況
+    pub fn status(&self) -> Option<&str> {
+        self.fields.get("status").and_then(Value::as_str)
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "id": self.id,
+            "fingerprint": self.fingerprint,
+            "jisx0402": self.jisx0402,
+            "time": self.time.map(format_datetime),
+            "type": self.disaster_type,
+            "address": self.address,
+            "fields": self.fields,
+            "first_seen": format_datetime(self.first_seen),
+            "last_seen": format_datetime(self.last_seen)
+        })
+    }
+
+    fn from_json(value: &Value) -> Option<Self> {
+        Some(IncidentRecord {
+            id: value["id"].as_str()?.to_string(),
+            fingerprint: value["fingerprint"].as_str()?.to_string(),
+            jisx0402: value["jisx0402"].as_str()?.to_string(),
+            time: value["time"].as_str().and_then(|time| DateTime::parse_from_rfc3339(time).ok()),
+            disaster_type: value["type"].as_str().unwrap_or_default().to_string(),
+            address: value["address"].as_str().unwrap_or_default().to_string(),
+            // 第1版の状態ファイルは出動状況のみを保持している
+            fields: match value["fields"].as_object() {
+                Some(fields) => fields.clone(),
+                None => tracked_fields(value),
+            },
+            first_seen: DateTime::parse_from_rfc3339(value["first_seen"].as_str()?).ok()?,
+            last_seen: DateTime::parse_from_rfc3339(value["last_seen"].as_str()?).ok()?,
+        })
+    }
+}
+
+/// 出動情報のフィールドの変化
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    /// 変化前の値（前回は無かった場合は`null`）
+    pub from: Value,
+    /// 変化後の値（今回は無い場合は`null`）
+    pub to: Value,
+}
+
+impl FieldChange {
+    pub fn to_json(&self) -> Value {
+        json!({ "field": self.field, "from": self.from, "to": self.to })
+    }
+}
+
+/// 出動情報を観測した結果
+#[derive(Debug, Clone, PartialEq)]
+pub enum Observation {
+    /// 初めて観測した出動情報
+    New,
+    /// 前回から`TRACKED_FIELDS`のいずれかが変わった出動情報
+    Changed(Vec<FieldChange>),
+    /// 前回から変化の無い出動情報
+    Unchanged,
+}
+
+impl Observation {
+    /// 出動状況の変化を返す
+    pub fn status_change(&self) -> Option<&FieldChange> {
+        match self {
+            Observation::Changed(changes) => changes.iter().find(|change| change.field == "status"),
+            _ => None,
+        }
+    }
+}
+
+/// 出動情報から`TRACKED_FIELDS`の値を取り出す（値が無いフィールドは含めない）
+fn tracked_fields(disaster: &Value) -> Map<String, Value> {
+    TRACKED_FIELDS
+        .iter()
+        .filter_map(|field| disaster.get(*field).filter(|value| !value.is_null()).map(|value| (field.to_string(), value.clone())))
+        .collect()
+}
+
+/// 前回と今回の`TRACKED_FIELDS`の値を比べ、変化したフィールドを返す
+fn diff_fields(previous: &Map<String, Value>, current: &Map<String, Value>) -> Vec<FieldChange> {
+    TRACKED_FIELDS
+        .iter()
+        .filter_map(|field| {
+            let from = previous.get(*field).cloned().unwrap_or(Value::Null);
+            let to = current.get(*field).cloned().unwrap_or(Value::Null);
+            (from != to).then(|| FieldChange { field: field.to_string(), from, to })
+        })
+        .collect()
+}
+
+/// 日時を秒単位のRFC 3339形式とする
+fn format_datetime(datetime: DateTime<FixedOffset>) -> String {
+    datetime.to_rfc3339_opts(SecondsFormat::Secs, false)
+}
+
+/// 64ビットのFNV-1aハッシュを16進数で返す（Rustの版によらず同じ値となるよう標準のハッシュ関数は使わない）
+fn fnv1a(text: &str) -> String {
+    let hash = text.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// 出動情報の指紋を求める関数
+///
+/// 出動状況は含めないため、鎮火などで状況が変わっても同じ出動情報として扱う。
+/// 出動日時を含めるため、同じ住所・種別の出動が繰り返されても別の出動情報として扱う。
+pub fn fingerprint(jisx0402: &str, time: &str, address: &str, disaster_type: &str) -> String {
+    fnv1a(&format!("{}\u{1f}{}\u{1f}{}\u{1f}{}", jisx0402, time, normalize_address(address), disaster_type))
+}
+
+/// 実行をまたいで出動情報の識別子と観測日時を保持する状態ファイル
+///
+/// 全ての出力形式は、このファイルが割り当てた識別子を共通して使用する。
+/// あわせて、前回の実行からの変化をイベントとして記録する。
+#[derive(Debug)]
+pub struct IncidentStore {
+    /// 状態ファイルの場所（`None`の場合はメモリ上でのみ保持する）
+    path: Option<PathBuf>,
+    /// 指紋から出動情報への対応
+    records: HashMap<String, IncidentRecord>,
+    /// 割り当て済みの識別子
+    ids: HashSet<String>,
+    /// この実行での観測日時
+    observed_at: DateTime<FixedOffset>,
+    /// 前回の実行での観測日時（初回の実行では`None`）
+    previous_observed_at: Option<DateTime<FixedOffset>>,
+    /// 次のイベントに付与する連番（実行をまたいで増え続ける）
+    next_sequence: u64,
+    /// この実行で発生したイベント
+    events: Vec<Value>,
+    /// この実行で取得できたソースの地方公共団体コード
+    observed_sources: HashSet<String>,
+    /// 消えた出動情報の判定を済ませたか
+    finished: bool,
+}
+
+impl IncidentStore {
+    /// 状態ファイルを使用せず、空の状態から始める（`observed_at`はこの実行での観測日時）
+    pub fn new(observed_at: DateTime<FixedOffset>) -> Self {
+        IncidentStore {
+            path: None,
+            records: HashMap::new(),
+            ids: HashSet::new(),
+            observed_at,
+            previous_observed_at: None,
+            next_sequence: 1,
+            events: vec![],
+            observed_sources: HashSet::new(),
+            finished: false,
+        }
+    }
+
+    /// 状態ファイルを読み込む（`observed_at`はこの実行での観測日時）
+    ///
+    /// ファイルが存在しない・読み込めない場合は空の状態から始める。
+    pub fn load(path: &Path, observed_at: DateTime<FixedOffset>) -> Self {
+        let mut store = IncidentStore { path: Some(path.to_path_buf()), ..Self::new(observed_at) };
+        let data = match fs::read_to_string(path) {
+            Ok(data) => data,
+            Err(_) => {
+                println!("出動情報の状態ファイルが無いため、新たに作成します: {}", path.display());
+                return store;
+            }
+        };
+        let json: Value = match serde_json::from_str(&data) {
+            Ok(json) => json,
+            Err(e) => {
+                eprintln!("出動情報の状態ファイルを読み込めなかったため、新たに作成します: {}: {}", path.display(), e);
+                return store;
+            }
+        };
+        store.previous_observed_at = json["updated_at"].as_str().and_then(|time| DateTime::parse_from_rfc3339(time).ok());
+        store.next_sequence = json["next_sequence"].as_u64().unwrap_or(1);
+        for record in json["incidents"].as_array().into_iter().flatten().filter_map(IncidentRecord::from_json) {
+            store.ids.insert(record.id.clone());
+            store.records.insert(record.fingerprint.clone(), record);
+        }
+        store
+    }
+
+    /// 状態ファイルの場所（メモリ上でのみ保持する場合は`None`）
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// この実行での観測日時
+    pub fn observed_at(&self) -> DateTime<FixedOffset> {
+        self.observed_at
+    }
+
+    /// 前回の実行での観測日時
+    pub fn previous_observed_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.previous_observed_at
+    }
+
+    /// 保持している出動情報
+    pub fn records(&self) -> impl Iterator<Item = &IncidentRecord> {
+        self.records.values()
+    }
+
+    /// 出動日時と地方公共団体コードから、まだ使われていない識別子を求める
+    fn new_id(&self, time: DateTime<FixedOffset>, jisx0402: &str) -> String {
+        let base = format!(
+            "{}{:02}{:02}{:02}{:02}-{}",
+            time.year(),
+            time.month(),
+            time.day(),
+            time.hour(),
+            time.minute(),
+            jisx0402
+        );
+        // 同じ時刻に同じ市区町村で複数の出動がある場合は、2件目以降に連番を付与する
+        (0..)
+            .map(|count| if count == 0 { base.clone() } else { format!("{}-{:02}", base, count) })
+            .find(|id| !self.ids.contains(id))
+            .unwrap()
+    }
+
+    /// 連番を付与してイベントを記録する
+    fn push_event(&mut self, event: &str, record: &IncidentRecord, changes: &[FieldChange]) {
+        self.events.push(json!({
+            "sequence": self.next_sequence,
+            "event": event,
+            "id": record.id,
+            "jisx0402": record.jisx0402,
+            "observed_at": format_datetime(self.observed_at),
+            "time": record.time.map(format_datetime),
+            "type": record.disaster_type,
+            "address": record.address,
+            "status": record.status(),
+            "first_seen": format_datetime(record.first_seen),
+            "last_seen": format_datetime(record.last_seen),
+            "changes": changes.iter().map(FieldChange::to_json).collect::<Vec<_>>()
+        }));
+        self.next_sequence += 1;
+    }
+
+    /// ソースを取得できたことを記録する（出動情報が無い場合も含む）
+    ///
+    /// 取得に失敗したソースの出動情報は、消えたものとして扱わない。
+    pub fn mark_source(&mut self, jisx0402: &str) {
+        self.observed_sources.insert(jisx0402.to_string());
+    }
+
+    /// 出動情報を観測し、その識別子と観測結果を返す関数
+    ///
+    /// 同じ指紋の出動情報があればその識別子を引き継ぎ、最後に観測した日時と`TRACKED_FIELDS`の値を更新する。
+    /// 新たな出動情報は`added`、鎮火などの終了を表す状況に変わった出動情報は`resolved`、
+    /// それ以外の変化は`changed`のイベントとして記録する。
+    pub fn observe(&mut self, jisx0402: &str, disaster: &Value) -> (String, Observation) {
+        self.mark_source(jisx0402);
+        let time_str = disaster["time"].as_str().unwrap_or_default();
+        let disaster_type = disaster["type"].as_str().unwrap_or_default();
+        let address = disaster["address"].as_str().unwrap_or_default();
+        let fields = tracked_fields(disaster);
+        let time = incident_datetime(time_str, self.observed_at);
+
+        // 出動時刻を解釈できない場合は、時刻の表記そのものを指紋に使用する
+        let time_key = time.map_or_else(|| time_str.to_string(), format_datetime);
+        let fingerprint = fingerprint(jisx0402, &time_key, address, disaster_type);
+
+        if let Some(record) = self.records.get_mut(&fingerprint) {
+            record.last_seen = self.observed_at;
+            let changes = diff_fields(&record.fields, &fields);
+            if changes.is_empty() {
+                return (record.id.clone(), Observation::Unchanged);
+            }
+            record.fields = fields;
+            let record = record.clone();
+            let resolved = record.status().and_then(IncidentStatus::from_str_opt).is_some_and(|status| status.is_resolved());
+            let status_changed = changes.iter().any(|change| change.field == "status");
+            self.push_event(if resolved && status_changed { "resolved" } else { "changed" }, &record, &changes);
+            return (record.id, Observation::Changed(changes));
+        }
+
+        let id = self.new_id(time.unwrap_or(self.observed_at), jisx0402);
+        let record = IncidentRecord {
+            id: id.clone(),
+            fingerprint: fingerprint.clone(),
+            jisx0402: jisx0402.to_string(),
+            time,
+            disaster_type: disaster_type.to_string(),
+            address: normalize_address(address),
+            fields,
+            first_seen: self.observed_at,
+            last_seen: self.observed_at,
+        };
+        self.push_event("added", &record, &[]);
+        self.ids.insert(id.clone());
+        self.records.insert(fingerprint, record);
+        (id, Observation::New)
+    }
+
+    /// 前回の実行で観測し、今回は観測しなかった出動情報を`disappeared`のイベントとして記録する
+    ///
+    /// 今回取得できなかったソースの出動情報は対象としない。2回目以降の呼び出しでは何もしない。
+    pub fn finish_run(&mut self) {
+        if self.finished {
+            return;
+        }
+        self.finished = true;
+        let Some(previous_observed_at) = self.previous_observed_at else {
+            return;
+        };
+        let mut disappeared = self
+            .records
+            .values()
+            .filter(|record| record.last_seen == previous_observed_at && self.observed_sources.contains(&record.jisx0402))
+            .cloned()
+            .collect::<Vec<_>>();
+        disappeared.sort_by(|a, b| a.id.cmp(&b.id));
+        for record in disappeared {
+            self.push_event("disappeared", &record, &[]);
+        }
+    }
+
+    /// この実行で発生したイベントを連番の順に返す関数
+    pub fn events(&mut self) -> &[Value] {
+        self.finish_run();
+        &self.events
+    }
+
+    /// 最後に観測してから`RETENTION_DAYS`日を過ぎた出動情報を削除し、状態ファイルへ書き出す関数
+    ///
+    /// 状態ファイルの場所が無い場合は書き出さない。
+    pub fn save(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.finish_run();
+        let expires_at = self.observed_at - Duration::days(RETENTION_DAYS);
+        self.records.retain(|_, record| record.last_seen >= expires_at);
+        self.ids = self.records.values().map(|record| record.id.clone()).collect();
+
+        let mut incidents = self.records.values().collect::<Vec<_>>();
+        incidents.sort_by(|a, b| a.id.cmp(&b.id));
+        let output = json!({
+            "version": STATE_VERSION,
+            "updated_at": format_datetime(self.observed_at),
+            "next_sequence": self.next_sequence,
+            "incidents": incidents.iter().map(|record| record.to_json()).collect::<Vec<_>>()
+        });
+
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        output::write_atomic(path, serde_json::to_string_pretty(&output)?.as_bytes())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::feed::jst;
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<FixedOffset> {
+        jst().with_ymd_and_hms(2026, 10, day, hour, minute, 0).unwrap()
+    }
+
+    fn disaster(time: &str, address: &str, status: &str) -> Value {
+        json!({ "type": "建物火災", "address": address, "time": time, "status": status })
+    }
+
+    /// テストごとの一時的な状態ファイルの場所
+    fn temp_state_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("emergency_dispatch_state_{}_{}.json", std::process::id(), name));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    fn event_kinds(store: &mut IncidentStore) -> Vec<(String, String)> {
+        store
+            .events()
+            .iter()
+            .map(|event| (event["event"].as_str().unwrap().to_string(), event["id"].as_str().unwrap().to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn keeps_the_id_across_runs() {
+        let path = temp_state_path("keeps_the_id_across_runs");
+        let mut first = IncidentStore::load(&path, at(19, 10, 5));
+        let (id, observation) = first.observe("011002", &disaster("10:00", "北海道札幌市中央区北1条西2丁目", "dispatched"));
+        assert_eq!((id.as_str(), observation), ("202610191000-011002", Observation::New));
+        first.save().unwrap();
+
+        let mut second = IncidentStore::load(&path, at(19, 10, 20));
+        let (id, observation) = second.observe("011002", &disaster("10:00", "北海道札幌市中央区北1条西2丁目", "extinguished"));
+        assert_eq!(id, "202610191000-011002");
+        assert_eq!(observation.status_change().map(|change| change.to.clone()), Some(json!("extinguished")));
+        assert_eq!(event_kinds(&mut second), vec![("resolved".to_string(), id)]);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn resolves_a_time_before_midnight_to_the_same_date() {
+        let path = temp_state_path("resolves_a_time_before_midnight_to_the_same_date");
+        let mut first = IncidentStore::load(&path, at(19, 23, 58));
+        let (id, _) = first.observe("011002", &disaster("23:55", "北海道札幌市中央区北1条西2丁目", "dispatched"));
+        assert_eq!(id, "202610192355-011002");
+        first.save().unwrap();
+
+        // 日付が変わった後の実行でも、前日の出動として同じ出動情報とする
+        let mut second = IncidentStore::load(&path, at(20, 0, 10));
+        let (id, observation) = second.observe("011002", &disaster("23:55", "北海道札幌市中央区北1条西2丁目", "dispatched"));
+        assert_eq!((id.as_str(), observation), ("202610192355-011002", Observation::Unchanged));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn adds_a_suffix_when_ids_collide() {
+        let mut store = IncidentStore::new(at(19, 10, 5));
+        let (first, _) = store.observe("011002", &disaster("10:00", "北海道札幌市中央区北1条西2丁目", "dispatched"));
+        let (second, _) = store.observe("011002", &disaster("10:00", "北海道札幌市北区北7条西5丁目", "dispatched"));
+        let (third, _) = store.observe("011002", &disaster("10:00", "北海道札幌市東区北10条東1丁目", "dispatched"));
+        assert_eq!(first, "202610191000-011002");
+        assert_eq!(second, "202610191000-011002-01");
+        assert_eq!(third, "202610191000-011002-02");
+        // 住所の表記揺れは指紋に影響しない
+        assert_eq!(
+            fingerprint("011002", "10:00", "北海道札幌市中央区北１条西２丁目", "建物火災"),
+            fingerprint("011002", "10:00", "北海道札幌市中央区北1条西2丁目", "建物火災")
+        );
+    }
+
+    #[test]
+    fn prunes_incidents_not_seen_for_the_retention_period() {
+        let path = temp_state_path("prunes_incidents_not_seen_for_the_retention_period");
+        let mut first = IncidentStore::load(&path, at(1, 10, 5));
+        first.observe("011002", &disaster("10:00", "北海道札幌市中央区北1条西2丁目", "dispatched"));
+        first.save().unwrap();
+
+        let mut within = IncidentStore::load(&path, at(1 + RETENTION_DAYS as u32, 10, 5));
+        within.save().unwrap();
+        assert_eq!(within.len(), 1);
+
+        let mut expired = IncidentStore::load(&path, at(2 + RETENTION_DAYS as u32, 10, 5));
+        expired.save().unwrap();
+        assert!(expired.is_empty());
+        assert!(IncidentStore::load(&path, at(2 + RETENTION_DAYS as u32, 10, 6)).is_empty());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn records_disappeared_incidents_only_for_fetched_sources() {
+        let path = temp_state_path("records_disappeared_incidents_only_for_fetched_sources");
+        let mut first = IncidentStore::load(&path, at(19, 10, 5));
+        let (kept, _) = first.observe("011002", &disaster("10:00", "北海道札幌市中央区北1条西2丁目", "dispatched"));
+        let (gone, _) = first.observe("011002", &disaster("10:01", "北海道札幌市北区北7条西5丁目", "dispatched"));
+        first.observe("012025", &disaster("10:02", "北海道函館市五稜郭町", "dispatched"));
+        first.save().unwrap();
+
+        // 函館市は取得に失敗したため、出動情報が消えたものとはしない
+        let mut second = IncidentStore::load(&path, at(19, 10, 20));
+        let (id, _) = second.observe("011002", &disaster("10:00", "北海道札幌市中央区北1条西2丁目", "dispatched"));
+        assert_eq!(id, kept);
+        assert_eq!(event_kinds(&mut second), vec![("disappeared".to_string(), gone)]);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn records_added_changed_and_resolved_events_in_sequence() {
+        let path = temp_state_path("records_added_changed_and_resolved_events_in_sequence");
+        let mut first = IncidentStore::load(&path, at(19, 10, 5));
+        let (id, _) = first.observe("011002", &disaster("10:00", "北海道札幌市中央区北1条西2丁目", "dispatched"));
+        // 同じ実行で再び観測しても、変化が無ければイベントは増えない
+        first.observe("011002", &disaster("10:00", "北海道札幌市中央区北1条西2丁目", "dispatched"));
+        let added = first.events().to_vec();
+        assert_eq!(added.len(), 1);
+        assert_eq!((added[0]["event"].clone(), added[0]["sequence"].clone()), (json!("added"), json!(1)));
+        assert_eq!(added[0]["changes"], json!([]));
+        first.save().unwrap();
+
+        // 詳細だけの変化は`changed`とする
+        let mut second = IncidentStore::load(&path, at(19, 10, 20));
+        let mut detailed = disaster("10:00", "北海道札幌市中央区北1条西2丁目", "dispatched");
+        detailed["detail"] = json!("消防車5台が出動しています。");
+        let (_, observation) = second.observe("011002", &detailed);
+        assert_eq!(observation.status_change(), None);
+        let changed = second.events().to_vec();
+        assert_eq!(event_kinds(&mut second), vec![("changed".to_string(), id.clone())]);
+        assert_eq!(changed[0]["sequence"], json!(2));
+        assert_eq!(
+            changed[0]["changes"],
+            json!([{ "field": "detail", "from": null, "to": "消防車5台が出動しています。" }])
+        );
+        second.save().unwrap();
+
+        // 終了を表す状況への変化は`resolved`とする
+        let mut third = IncidentStore::load(&path, at(19, 10, 35));
+        detailed["status"] = json!("extinguished");
+        third.observe("011002", &detailed);
+        let resolved = third.events().to_vec();
+        assert_eq!(event_kinds(&mut third), vec![("resolved".to_string(), id)]);
+        assert_eq!(resolved[0]["sequence"], json!(3));
+        assert_eq!(resolved[0]["observed_at"], json!("2026-10-19T10:35:00+09:00"));
+        assert_eq!(
+            resolved[0]["changes"],
+            json!([{ "field": "status", "from": "dispatched", "to": "extinguished" }])
+        );
+        fs::remove_file(&path).unwrap();
+    }
+}
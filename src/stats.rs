@@ -0,0 +1,252 @@
+use std::collections::{BTreeMap, HashMap};
+use chrono::{DateTime, Duration, DurationRound, FixedOffset, NaiveDate, SecondsFormat};
+use serde_json::{json, Value};
+
+use crate::category::{classify, Category};
+use crate::feed::incident_datetime;
+use crate::jisx0402;
+
+/// 集計する期間（名前と長さ（時間）、1日ごとの件数を求めるか）
+pub const WINDOWS: [(&str, i64, bool); 3] = [("24h", 24, false), ("7d", 24 * 7, true), ("30d", 24 * 30, true)];
+
+/// 集計に使用する出動情報1件
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatsIncident {
+    /// 出動情報の識別子（重複を除くために使用する）
+    pub id: String,
+    /// 6桁の地方公共団体コード
+    pub jisx0402: String,
+    /// 出動日時
+    pub time: DateTime<FixedOffset>,
+    /// 出動種別の大分類
+    pub category: Category,
+}
+
+impl StatsIncident {
+    /// 市区町村ごとのファイルの出動情報から作成する（出動時刻を解釈できない場合は`observed_at`とする）
+    pub fn from_disaster(jisx0402: &str, disaster: &Value, observed_at: DateTime<FixedOffset>) -> Option<Self> {
+        let id = disaster["id"].as_str()?;
+        let time = disaster["time"]
+            .as_str()
+            .and_then(|time| incident_datetime(time, observed_at))
+            .unwrap_or(observed_at);
+        Some(StatsIncident {
+            id: id.to_string(),
+            jisx0402: jisx0402.to_string(),
+            time,
+            category: classify(disaster["type"].as_str().unwrap_or_default()).0,
+        })
+    }
+
+    /// 履歴の`new`の行から作成する（それ以外の行は`None`）
+    pub fn from_archive_entry(entry: &Value) -> Option<Self> {
+        if entry["event"] != "new" {
+            return None;
+        }
+        let observed_at = DateTime::parse_from_rfc3339(entry["observed_at"].as_str()?).ok()?;
+        Self::from_disaster(entry["jisx0402"].as_str()?, entry, observed_at)
+    }
+}
+
+/// 出動情報の一覧から、期間ごとの集計を生成する関数
+///
+/// 期間ごとに市区町村・都道府県・出動種別の大分類ごとの件数と、1時間ごとの件数（ヒストグラム）を求める。
+/// 7日間・30日間の期間では、1日ごとの件数もあわせて求める。
+/// 同じ識別子の出動情報は1件として数える。
+pub fn generate(incidents: &[StatsIncident], now: DateTime<FixedOffset>) -> Value {
+    let mut unique: HashMap<&str, &StatsIncident> = HashMap::new();
+    for incident in incidents {
+        unique.entry(incident.id.as_str()).or_insert(incident);
+    }
+    let mut incidents = unique.into_values().collect::<Vec<_>>();
+    incidents.sort_by(|a, b| (a.time, &a.id).cmp(&(b.time, &b.id)));
+
+    let mut windows = serde_json::Map::new();
+    for (name, hours, with_daily) in WINDOWS {
+        let from = now - Duration::hours(hours);
+        let in_window = incidents
+            .iter()
+            .filter(|incident| incident.time > from && incident.time <= now)
+            .copied()
+            .collect::<Vec<_>>();
+        let mut summary = window(&in_window, from, now);
+        if with_daily {
+            summary["daily"] = json!(daily(&in_window, from, now));
+        }
+        windows.insert(name.to_string(), summary);
+    }
+
+    json!({
+        "generated_at": now.to_rfc3339_opts(SecondsFormat::Secs, false),
+        "windows": windows
+    })
+}
+
+/// 1つの期間の集計
+fn window(incidents: &[&StatsIncident], from: DateTime<FixedOffset>, to: DateTime<FixedOffset>) -> Value {
+    let mut by_municipality: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut by_prefecture: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut by_category: BTreeMap<&str, usize> = Category::ALL.iter().map(|c| (c.as_str(), 0)).collect();
+    for incident in incidents {
+        *by_municipality.entry(&incident.jisx0402).or_default() += 1;
+        if let Some(prefecture_code) = incident.jisx0402.get(..2) {
+            *by_prefecture.entry(prefecture_code).or_default() += 1;
+        }
+        *by_category.entry(incident.category.as_str()).or_default() += 1;
+    }
+
+    let by_municipality = by_municipality
+        .into_iter()
+        .map(|(code, count)| {
+            let government = jisx0402::lookup(code);
+            (code.to_string(), json!({
+                "prefecture": government.map(|g| g.prefecture),
+                "name": government.map(|g| g.display_name()),
+                "count": count
+            }))
+        })
+        .collect::<serde_json::Map<_, _>>();
+    let by_prefecture = by_prefecture
+        .into_iter()
+        .map(|(prefecture_code, count)| {
+            (prefecture_code.to_string(), json!({
+                "name": jisx0402::prefecture_of(prefecture_code).map(|g| g.prefecture),
+                "count": count
+            }))
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+    json!({
+        "from": from.to_rfc3339_opts(SecondsFormat::Secs, false),
+        "to": to.to_rfc3339_opts(SecondsFormat::Secs, false),
+        "total": incidents.len(),
+        "by_municipality": by_municipality,
+        "by_prefecture": by_prefecture,
+        "by_category": by_category,
+        "hourly": hourly(incidents, from, to)
+    })
+}
+
+/// 1時間ごとの件数（各時間帯の開始日時・件数・出動種別の大分類ごとの件数）
+///
+/// 時間帯は正時で区切り、期間の開始を含む時間帯から終了を含む時間帯までを、件数が0の時間帯も含めて並べる。
+fn hourly(incidents: &[&StatsIncident], from: DateTime<FixedOffset>, to: DateTime<FixedOffset>) -> Vec<Value> {
+    let hour = Duration::hours(1);
+    let (Ok(first), Ok(last)) = (from.duration_trunc(hour), to.duration_trunc(hour)) else {
+        return vec![];
+    };
+    let bucket_count = ((last - first).num_hours() + 1).max(0) as usize;
+    let mut buckets: Vec<BTreeMap<&str, usize>> = vec![BTreeMap::new(); bucket_count];
+    for incident in incidents {
+        let Ok(start) = incident.time.duration_trunc(hour) else {
+            continue;
+        };
+        let index = (start - first).num_hours();
+        if let Some(bucket) = usize::try_from(index).ok().and_then(|index| buckets.get_mut(index)) {
+            *bucket.entry(incident.category.as_str()).or_default() += 1;
+        }
+    }
+
+    buckets
+        .into_iter()
+        .enumerate()
+        .map(|(index, by_category)| {
+            json!({
+                "start": (first + hour * index as i32).to_rfc3339_opts(SecondsFormat::Secs, false),
+                "count": by_category.values().sum::<usize>(),
+                "by_category": by_category
+            })
+        })
+        .collect()
+}
+
+/// 1日ごとの件数（各日の日付・件数・出動種別の大分類ごとの件数）
+///
+/// 日本標準時の日付で区切り、期間の開始を含む日から終了を含む日までを、件数が0の日も含めて並べる。
+fn daily(incidents: &[&StatsIncident], from: DateTime<FixedOffset>, to: DateTime<FixedOffset>) -> Vec<Value> {
+    let last = to.date_naive();
+    let mut buckets: BTreeMap<NaiveDate, BTreeMap<&str, usize>> = from
+        .date_naive()
+        .iter_days()
+        .take_while(|date| *date <= last)
+        .map(|date| (date, BTreeMap::new()))
+        .collect();
+    for incident in incidents {
+        if let Some(bucket) = buckets.get_mut(&incident.time.date_naive()) {
+            *bucket.entry(incident.category.as_str()).or_default() += 1;
+        }
+    }
+
+    buckets
+        .into_iter()
+        .map(|(date, by_category)| {
+            json!({
+                "date": date.format("%Y-%m-%d").to_string(),
+                "count": by_category.values().sum::<usize>(),
+                "by_category": by_category
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::feed::jst;
+
+    fn incident(id: &str, jisx0402: &str, time: DateTime<FixedOffset>, category: Category) -> StatsIncident {
+        StatsIncident { id: id.to_string(), jisx0402: jisx0402.to_string(), time, category }
+    }
+
+    #[test]
+    fn adds_daily_buckets_to_the_longer_windows() {
+        let now = jst().with_ymd_and_hms(2026, 10, 19, 12, 34, 0).unwrap();
+        let incidents = [
+            incident("a", "011002", now - Duration::hours(1), Category::Fire),
+            incident("b", "011002", now - Duration::days(2), Category::Rescue),
+        ];
+        let stats = generate(&incidents, now);
+
+        assert!(stats["windows"]["24h"].get("daily").is_none());
+        let daily = stats["windows"]["7d"]["daily"].as_array().unwrap();
+        // 期間の開始を含む10月12日から、終了を含む10月19日まで
+        assert_eq!(daily.len(), 8);
+        assert_eq!(daily[0]["date"], json!("2026-10-12"));
+        assert_eq!(daily[5], json!({ "date": "2026-10-17", "count": 1, "by_category": { "rescue": 1 } }));
+        assert_eq!(daily[7], json!({ "date": "2026-10-19", "count": 1, "by_category": { "fire": 1 } }));
+        assert_eq!(stats["windows"]["30d"]["daily"].as_array().unwrap().len(), 31);
+    }
+
+    #[test]
+    fn counts_each_incident_once_within_the_window() {
+        let now = jst().with_ymd_and_hms(2026, 10, 19, 12, 34, 0).unwrap();
+        let incidents = [
+            incident("a", "011002", now - Duration::hours(1), Category::Fire),
+            // 同じ識別子の出動情報は1件として数える
+            incident("a", "011002", now - Duration::hours(2), Category::Fire),
+            incident("b", "012025", now - Duration::minutes(23 * 60 + 59), Category::Rescue),
+            // 期間の開始ちょうどの出動情報と、終了より後の出動情報は含めない
+            incident("c", "011002", now - Duration::hours(24), Category::Fire),
+            incident("d", "011002", now + Duration::minutes(1), Category::Fire),
+        ];
+        let stats = generate(&incidents, now);
+
+        let day = &stats["windows"]["24h"];
+        assert_eq!(day["from"], json!("2026-10-18T12:34:00+09:00"));
+        assert_eq!(day["total"], json!(2));
+        assert_eq!(day["by_municipality"]["011002"]["count"], json!(1));
+        assert_eq!(day["by_municipality"]["012025"]["count"], json!(1));
+        assert_eq!(day["by_prefecture"]["01"]["count"], json!(2));
+        assert_eq!(day["by_category"]["fire"], json!(1));
+        assert_eq!(day["by_category"]["hazmat"], json!(0));
+
+        // 10月18日12時台から10月19日12時台まで
+        let hourly = day["hourly"].as_array().unwrap();
+        assert_eq!(hourly.len(), 25);
+        assert_eq!(hourly[0], json!({ "start": "2026-10-18T12:00:00+09:00", "count": 1, "by_category": { "rescue": 1 } }));
+        assert_eq!(hourly[23], json!({ "start": "2026-10-19T11:00:00+09:00", "count": 1, "by_category": { "fire": 1 } }));
+        assert_eq!(stats["windows"]["7d"]["total"], json!(3));
+    }
+}
@@ -0,0 +1,116 @@
+/// 出動情報の状況
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IncidentStatus {
+    /// 出動中
+    Dispatched,
+    /// 鎮圧
+    UnderControl,
+    /// 鎮火
+    Extinguished,
+    /// 活動終了
+    Completed,
+    /// 誤報
+    FalseAlarm,
+}
+
+impl IncidentStatus {
+    /// 全ての状況
+    pub const ALL: [IncidentStatus; 5] = [
+        IncidentStatus::Dispatched,
+        IncidentStatus::UnderControl,
+        IncidentStatus::Extinguished,
+        IncidentStatus::Completed,
+        IncidentStatus::FalseAlarm,
+    ];
+
+    /// 出力に使用する識別子
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IncidentStatus::Dispatched => "dispatched",
+            IncidentStatus::UnderControl => "under_control",
+            IncidentStatus::Extinguished => "extinguished",
+            IncidentStatus::Completed => "completed",
+            IncidentStatus::FalseAlarm => "false_alarm",
+        }
+    }
+
+    /// 日本語の名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            IncidentStatus::Dispatched => "出動中",
+            IncidentStatus::UnderControl => "鎮圧",
+            IncidentStatus::Extinguished => "鎮火",
+            IncidentStatus::Completed => "終了",
+            IncidentStatus::FalseAlarm => "誤報",
+        }
+    }
+
+    /// 出力の識別子から状況を復元する
+    pub fn from_str_opt(s: &str) -> Option<Self> {
+        match s {
+            "dispatched" => Some(IncidentStatus::Dispatched),
+            "under_control" => Some(IncidentStatus::UnderControl),
+            "extinguished" => Some(IncidentStatus::Extinguished),
+            "completed" => Some(IncidentStatus::Completed),
+            "false_alarm" => Some(IncidentStatus::FalseAlarm),
+            _ => None,
+        }
+    }
+
+    /// 災害が終息したことを表す状況か
+    pub fn is_resolved(&self) -> bool {
+        matches!(
+            self,
+            IncidentStatus::Extinguished | IncidentStatus::Completed | IncidentStatus::FalseAlarm
+        )
+    }
+}
+
+/// 状況を表す語と状況の対応表（上から順に照合する）
+const STATUS_KEYWORDS: [(&str, IncidentStatus); 6] = [
+    ("誤報", IncidentStatus::FalseAlarm),
+    ("ではありません", IncidentStatus::FalseAlarm),
+    ("鎮火", IncidentStatus::Extinguished),
+    ("鎮圧", IncidentStatus::UnderControl),
+    ("終了", IncidentStatus::Completed),
+    ("完了", IncidentStatus::Completed),
+];
+
+/// 出動情報の文面から状況を判定する関数
+///
+/// 「火災ではありません」のような打ち消しは誤報とし、
+/// 「鎮火」「鎮圧」「終了」「完了」「誤報」のいずれも含まれない場合は出動中とみなす。
+pub fn detect_status(text: &str) -> IncidentStatus {
+    STATUS_KEYWORDS
+        .iter()
+        .find(|(keyword, _)| text.contains(keyword))
+        .map(|(_, status)| *status)
+        .unwrap_or(IncidentStatus::Dispatched)
+}
+
+/// 「発生した建物火災は鎮火しました」のような終息を伝える文から、出動種別の部分を取り出す関数
+///
+/// 状況を表す語より前の部分を出動種別とみなし、前後の「発生した」「は」などを取り除く。
+pub fn strip_status_phrase(text: &str) -> &str {
+    let end = STATUS_KEYWORDS
+        .iter()
+        .filter_map(|(keyword, _)| text.find(keyword))
+        .min()
+        .unwrap_or(text.len());
+    text[..end]
+        .trim()
+        .trim_start_matches("発生した")
+        .trim_end_matches(['は', 'が', 'の', '、', '　', ' '])
+        .trim()
+}
+
+/// 「…の火災は、火災ではありません」のような誤報を伝える文から、打ち消された出動種別を取り出す関数
+///
+/// 「ではありません」の直前の、助詞や読点より後の部分を出動種別とみなす。
+pub fn false_alarm_type(text: &str) -> Option<&str> {
+    let (before, _) = text.split_once("ではありません")?;
+    let start = before.rfind(['は', 'の', 'が', 'で', '、']).map_or(0, |index| {
+        index + before[index..].chars().next().map_or(0, char::len_utf8)
+    });
+    Some(before[start..].trim()).filter(|disaster_type| !disaster_type.is_empty())
+}
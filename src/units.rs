@@ -0,0 +1,144 @@
+use serde_json::{json, Value};
+
+/// 出動した車両・部隊の種別と数
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unit {
+    /// 車両・部隊の種別（ソースの表記のまま。例: 「消防車」「救急隊」）
+    pub kind: String,
+    /// 台数・隊数（ソースに記載が無い場合は`None`）
+    pub count: Option<u32>,
+}
+
+impl Unit {
+    pub fn to_json(&self) -> Value {
+        json!({
+            "kind": self.kind,
+            "count": self.count
+        })
+    }
+}
+
+/// 車両・部隊の種別として認識する語
+///
+/// 長い語を先に照合するため、「救助工作車」を「救助」より、「救急隊」を「救急」より前に並べること。
+const UNIT_KINDS: [&str; 16] = [
+    "救助工作車",
+    "はしご車",
+    "ポンプ車",
+    "化学車",
+    "救急車",
+    "消防車",
+    "指揮車",
+    "消防艇",
+    "ヘリコプター",
+    "ヘリ",
+    "救急隊",
+    "救助隊",
+    "消防隊",
+    "指揮隊",
+    "航空隊",
+    "消防団",
+];
+
+lazy_static::lazy_static! {
+    static ref COUNT_RE: regex::Regex = regex::Regex::new(r"^(?:等)?(\d+)(?:台|隊|機|艇)").unwrap();
+}
+
+/// 文面から出動した車両・部隊を取り出す関数
+///
+/// 「消防車5台」のように直後に数が書かれている場合は`count`に格納する。
+/// 同じ種別が複数回現れた場合は最初のものだけを採用する。
+pub fn extract_units(text: &str) -> Vec<Unit> {
+    let mut units: Vec<Unit> = vec![];
+    let mut rest = text;
+
+    // 文中で最も前にある種別から順に取り出す（同じ位置の場合は長い語を優先）
+    while let Some((pos, kind)) = UNIT_KINDS
+        .iter()
+        .filter_map(|kind| rest.find(kind).map(|pos| (pos, *kind)))
+        .min_by_key(|(pos, kind)| (*pos, usize::MAX - kind.len()))
+    {
+        rest = &rest[pos + kind.len()..];
+        if units.iter().any(|unit| unit.kind == kind) {
+            continue;
+        }
+        let count = COUNT_RE
+            .captures(rest)
+            .and_then(|caps| caps[1].parse().ok());
+        units.push(Unit { kind: kind.to_string(), count });
+    }
+
+    units
+}
+
+/// 出動種別より後ろに続く文を、詳細として取り出す関数
+///
+/// `sentence`は出動種別から始まる文（例: 「建物火災が発生し、消防車が出動しています。」）、
+/// `disaster_type`はそこから取り出した出動種別。取り出せる文が無い場合は`None`を返す。
+pub fn extract_detail(sentence: &str, disaster_type: &str) -> Option<String> {
+    let sentence = sentence.trim();
+    let rest = sentence
+        .find(disaster_type.trim())
+        .filter(|_| !disaster_type.trim().is_empty())
+        .map_or(sentence, |pos| &sentence[pos + disaster_type.trim().len()..])
+        .trim();
+    if rest.is_empty() { None } else { Some(rest.to_string()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit(kind: &str, count: Option<u32>) -> Unit {
+        Unit { kind: kind.to_string(), count }
+    }
+
+    #[test]
+    fn extracts_units_in_order_with_counts() {
+        assert_eq!(
+            extract_units("消防車5台、救急車1台、ヘリコプター1機が出動しています。"),
+            vec![unit("消防車", Some(5)), unit("救急車", Some(1)), unit("ヘリコプター", Some(1))]
+        );
+        assert_eq!(extract_units("消防車等3台が出動"), vec![unit("消防車", Some(3))]);
+    }
+
+    #[test]
+    fn prefers_longer_kind_at_same_position() {
+        assert_eq!(
+            extract_units("救助工作車1台と救助隊2隊"),
+            vec![unit("救助工作車", Some(1)), unit("救助隊", Some(2))]
+        );
+        assert_eq!(extract_units("ヘリが出動"), vec![unit("ヘリ", None)]);
+    }
+
+    #[test]
+    fn keeps_first_occurrence_of_each_kind() {
+        assert_eq!(
+            extract_units("消防車2台、救急車、消防車4台"),
+            vec![unit("消防車", Some(2)), unit("救急車", None)]
+        );
+        assert!(extract_units("現在、活動中です。").is_empty());
+    }
+
+    #[test]
+    fn extracts_detail_after_disaster_type() {
+        assert_eq!(
+            extract_detail(" 建物火災が発生し、消防車が出動しています。", "建物火災"),
+            Some("が発生し、消防車が出動しています。".to_string())
+        );
+        assert_eq!(extract_detail("救急支援", "救急支援"), None);
+    }
+
+    #[test]
+    fn falls_back_to_whole_sentence() {
+        assert_eq!(
+            extract_detail("消防車が出動しています。", "建物火災"),
+            Some("消防車が出動しています。".to_string())
+        );
+        assert_eq!(
+            extract_detail("消防車が出動しています。", " "),
+            Some("消防車が出動しています。".to_string())
+        );
+        assert_eq!(extract_detail("  ", ""), None);
+    }
+}